@@ -0,0 +1,295 @@
+use std::{ops::RangeInclusive, path::PathBuf, time::Duration};
+
+use console::style;
+use utils::bench::print_bench;
+use utils::io::input::{input_candidates, InputSource};
+use utils::io::output::{print_solution, AnswerCheck, Output, OutputFormat};
+use utils::prelude::*;
+use utils::puzzle::Answers;
+
+const USAGE: &str = "\
+Run the solutions of one or more days
+
+USAGE:
+  aoc run <DAYS> [OPTIONS] [INPUT]
+
+ARGS:
+  <DAYS>   a single day (16), a range of days (1..5, 1..=5 or 1-5) or 'all'
+  [INPUT]  input file, or '-' for stdin, only for a single day (default:
+           $AOC_INPUT_DIR/dayNN.txt, inputs/dayNN.txt or day_NN/input/puzzle.txt,
+           whichever exists)
+
+OPTIONS:
+  --example                use the example inputs and parameters
+  --part <PART>            only run part 1 or part 2
+  --format <FORMAT>        json, plain or pretty (default: pretty)
+  --param <KEY=VALUE>      override a puzzle parameter, can be repeated
+  --bench <RUNS>           time parsing and both parts over a number of runs
+  --bench-format <FORMAT>  table, csv or json (default: table)
+  --check                  compare the answers with the ones in the answers file
+  --record                 like --check, but also store answers that are not
+                           in the answers file yet
+  --answers <FILE>         answers file used by --check (default: answers.toml)
+  -h, --help               print this message
+";
+
+fn puzzles() -> Vec<&'static dyn Puzzle> {
+    vec![
+        &day_01::Day01,
+        &day_02::Day02,
+        &day_03::Day03,
+        &day_04::Day04,
+        &day_05::Day05,
+        &day_06::Day06,
+        &day_07::Day07,
+        &day_08::Day08,
+        &day_09::Day09,
+        &day_10::Day10,
+        &day_11::Day11,
+        &day_12::Day12,
+        &day_13::Day13,
+        &day_14::Day14,
+        &day_15::Day15,
+        &day_16::Day16,
+        &day_17::Day17,
+        &day_18::Day18,
+        &day_19::Day19,
+        &day_20::Day20,
+    ]
+}
+
+fn main() -> Result<()> {
+    init_tracing();
+
+    let mut pargs = pico_args::Arguments::from_env();
+    if pargs.contains(["-h", "--help"]) {
+        print!("{}", USAGE);
+        return Ok(());
+    }
+
+    match pargs.subcommand()?.as_deref() {
+        Some("run") => {}
+        Some(other) => return Err(parse_error("unknown command", other)),
+        None => {
+            print!("{}", USAGE);
+            return Ok(());
+        }
+    }
+
+    let (mut args, free) = Args::parse_options(pargs)?;
+    let days = match free.as_slice() {
+        [] => return Err(parse_error("expected the days to run, see --help", "")),
+        [days] => days,
+        [days, input] => {
+            args.input = Some(PathBuf::from(input));
+            days
+        }
+        [_, _, extra, ..] => return Err(parse_error("only a single input can be given", extra)),
+    };
+    let days = parse_days(days)?;
+
+    let selected = puzzles()
+        .into_iter()
+        .filter(|p| days.contains(&p.day()))
+        .collect::<Vec<_>>();
+
+    if selected.is_empty() {
+        return Err(Error::SolutionNotFound(format!(
+            "no solutions for days {}..={}",
+            days.start(),
+            days.end()
+        )));
+    }
+
+    if args.input.is_some() && selected.len() > 1 {
+        return Err(parse_error(
+            "an input file can only be given for a single day",
+            &format!("{}..={}", days.start(), days.end()),
+        ));
+    }
+
+    if let Some(runs) = args.bench {
+        let mut timings = Vec::new();
+        for puzzle in &selected {
+            let input = input_source(puzzle.day(), &args)?.read()?;
+            timings.extend(puzzle.bench(&input, &args, runs)?);
+        }
+        return print_bench(&timings, args.bench_format);
+    }
+
+    let mut check = if args.check {
+        Some(
+            AnswerCheck::load(&args.answers)?
+                .quiet(args.format != OutputFormat::Pretty)
+                .record(args.record),
+        )
+    } else {
+        None
+    };
+
+    let results = selected
+        .iter()
+        .map(|puzzle| run_day(*puzzle, &args, check.as_mut()))
+        .collect::<Vec<_>>();
+
+    if results.len() > 1 && args.format == OutputFormat::Pretty {
+        print_summary(&results);
+    }
+
+    check.map_or(Ok(()), AnswerCheck::finish)
+}
+
+fn parse_days(s: &str) -> Result<RangeInclusive<u8>> {
+    let range = if s == "all" {
+        1..=25
+    } else if let Some((from, to)) = s.split_once("..=") {
+        from.parse()?..=to.parse()?
+    } else if let Some((from, to)) = s.split_once("..") {
+        from.parse()?..=to.parse::<u8>()?.saturating_sub(1)
+    } else if let Some((from, to)) = s.split_once('-') {
+        from.parse()?..=to.parse()?
+    } else {
+        let day = s.parse()?;
+        day..=day
+    };
+
+    if range.is_empty() {
+        Err(parse_error("empty range of days", s))
+    } else {
+        Ok(range)
+    }
+}
+
+/// The input given on the command line, or else the first existing
+/// conventional input file of `day`.
+fn input_source(day: u8, args: &Args) -> Result<InputSource> {
+    if args.input.is_some() {
+        return InputSource::locate(day, args);
+    }
+
+    let candidates = input_candidates(day, args.example);
+    candidates
+        .iter()
+        .find(|path| path.is_file())
+        .map(|path| InputSource::File(path.clone()))
+        .ok_or(Error::InputNotFound(candidates))
+}
+
+struct DayResult {
+    day: u8,
+    answers: Result<Answers>,
+}
+
+#[tracing::instrument(level=tracing::Level::DEBUG,skip(puzzle,args,check),fields(day=puzzle.day()))]
+fn run_day(puzzle: &dyn Puzzle, args: &Args, check: Option<&mut AnswerCheck>) -> DayResult {
+    let day = puzzle.day();
+    if args.format == OutputFormat::Pretty {
+        println!("{}", style(format!("Day {:02}", day)).bold().underlined());
+    }
+
+    let answers = input_source(day, args).and_then(|source| {
+        let input = source.read()?;
+        let mut output = Output::new(args.format, day, source.name());
+        let answers = puzzle.solve(&input, args, &mut output, check);
+        output.finish()?;
+        answers
+    });
+    if let Err(error) = &answers {
+        print_solution(&format!("Day {:02}", day), &Err::<&str, _>(error));
+    }
+
+    DayResult { day, answers }
+}
+
+fn print_summary(results: &[DayResult]) {
+    println!();
+    println!(
+        "{:>4}  {:<20}  {:<20}  {:>10}",
+        style("Day").bold(),
+        style("Part 1").bold(),
+        style("Part 2").bold(),
+        style("Time").bold()
+    );
+
+    let total = results.iter().fold(Duration::ZERO, |total, result| {
+        let answer = |part: Part| {
+            let cell = match &result.answers {
+                Ok(answers) => match answers.parts.iter().find(|(p, _, _)| *p == part) {
+                    Some((_, Ok(answer), _)) => style(format!("{:<20}", answer)).green(),
+                    Some((_, Err(_), _)) => style(format!("{:<20}", "error")).red(),
+                    None => style(format!("{:<20}", "-")).dim(),
+                },
+                Err(_) => style(format!("{:<20}", "error")).red(),
+            };
+            cell.to_string()
+        };
+        let elapsed: Duration = match &result.answers {
+            Ok(answers) => {
+                answers.parse + answers.parts.iter().map(|(_, _, d)| *d).sum::<Duration>()
+            }
+            Err(_) => Duration::ZERO,
+        };
+
+        println!(
+            "{:>4}  {}  {}  {:>10}",
+            result.day,
+            answer(Part::One),
+            answer(Part::Two),
+            format!("{:.2?}", elapsed)
+        );
+        total + elapsed
+    });
+
+    println!(
+        "{:>4}  {:<20}  {:<20}  {:>10}",
+        "",
+        "",
+        "",
+        format!("{:.2?}", total)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("16").unwrap(), 16..=16);
+        assert_eq!(parse_days("1..5").unwrap(), 1..=4);
+        assert_eq!(parse_days("1..=5").unwrap(), 1..=5);
+        assert_eq!(parse_days("1-5").unwrap(), 1..=5);
+        assert_eq!(parse_days("all").unwrap(), 1..=25);
+        assert!(parse_days("5..5").is_err());
+        assert!(parse_days("x").is_err());
+    }
+
+    /// Every day has to run both parts unattended, as `aoc run all` and
+    /// `--bench` would otherwise hang.
+    #[test]
+    fn test_run_all_examples() {
+        let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let days = puzzles().iter().map(|p| p.day()).collect::<Vec<_>>();
+        assert_eq!(days, (1..=20).collect::<Vec<_>>());
+
+        for puzzle in puzzles() {
+            let path = workspace
+                .join(format!("day_{:02}", puzzle.day()))
+                .join("input/example.txt");
+            let args = Args {
+                example: true,
+                format: OutputFormat::Json,
+                input: Some(path.clone()),
+                ..Args::default()
+            };
+            let input = std::fs::read_to_string(&path).unwrap();
+            let mut output = Output::new(args.format, puzzle.day(), "example".to_owned());
+
+            let answers = puzzle.solve(&input, &args, &mut output, None).unwrap();
+            let parts = answers.parts.iter().map(|(p, _, _)| *p).collect::<Vec<_>>();
+            assert_eq!(parts, vec![Part::One, Part::Two], "day {}", puzzle.day());
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use itertools::Itertools;
+use tracing::Level;
+use utils::{parse, prelude::*};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<(i32, i32)>;
+    type Params = ();
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str, _: &Self::Params) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(pairs: &Self::Input) -> Result<i32> {
+        part_one(pairs)
+    }
+
+    fn part_two(pairs: &Self::Input) -> Result<i32> {
+        part_two(pairs)
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example::new("example", include_str!("../input/example.txt"))
+                .part_one("11")
+                .part_two("31"),
+        ]
+    }
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(pairs))]
+fn part_one(pairs: &[(i32, i32)]) -> Result<i32> {
+    let (mut a, mut b): (Vec<i32>, Vec<i32>) = pairs.iter().copied().unzip();
+    a.sort();
+    b.sort();
+    Ok(a.iter().zip(b).map(|(a, b)| (a - b).abs()).sum())
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(pairs))]
+fn part_two(pairs: &[(i32, i32)]) -> Result<i32> {
+    let (a, b): (Vec<i32>, Vec<i32>) = pairs.iter().copied().unzip();
+    let counts = b.iter().counts();
+    Ok(a.iter()
+        .map(|a| a * *counts.get(a).unwrap_or(&0) as i32)
+        .sum())
+}
+
+fn parse_input(input: &str) -> Result<Vec<(i32, i32)>> {
+    parse::lines(input, |line| {
+        let (a, b) = line
+            .split_once("   ")
+            .ok_or_else(|| parse_error("wrong delimiter", line))?;
+        let a = a.parse().map_err(|e| value_error(a, e))?;
+        let b = b.parse().map_err(|e| value_error(b, e))?;
+        Ok((a, b))
+    })
+}
+
+utils::example_tests!(Day01);
@@ -0,0 +1,90 @@
+use regex::Regex;
+use tracing::Level;
+use utils::prelude::*;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = String;
+    type Params = ();
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str, _: &Self::Params) -> Result<Self::Input> {
+        Ok(input.to_owned())
+    }
+
+    fn part_one(input: &Self::Input) -> Result<i64> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<i64> {
+        part_two(input)
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example::new("example", include_str!("../input/example.txt")).part_one("161"),
+            Example::new("example_2", include_str!("../input/example_2.txt")).part_two("48"),
+        ]
+    }
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(input))]
+fn part_one(input: &str) -> Result<i64> {
+    let r = Regex::new(r"mul\((?<a>[0-9]+),(?<b>[0-9]+)\)").unwrap();
+
+    Ok(r.captures_iter(input)
+        .map(|cap| {
+            let a: i64 = cap
+                .name("a")
+                .unwrap()
+                .as_str()
+                .parse()
+                .expect("a should be a number");
+            let b: i64 = cap
+                .name("b")
+                .unwrap()
+                .as_str()
+                .parse()
+                .expect("b should be a number");
+            a * b
+        })
+        .sum())
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(input))]
+fn part_two(input: &str) -> Result<i64> {
+    let r = Regex::new(r"mul\((?<a>[0-9]+),(?<b>[0-9]+)\)|do\(\)|don't\(\)").unwrap();
+
+    let (_, sum) = r
+        .captures_iter(input)
+        .fold((true, 0), |(enabled, sum), cap| {
+            match cap.get(0).unwrap().as_str() {
+                "do()" => (true, sum),
+                "don't()" => (false, sum),
+                _ if enabled => {
+                    let a: i64 = cap
+                        .name("a")
+                        .unwrap()
+                        .as_str()
+                        .parse()
+                        .expect("a should be a number");
+                    let b: i64 = cap
+                        .name("b")
+                        .unwrap()
+                        .as_str()
+                        .parse()
+                        .expect("b should be a number");
+                    (enabled, sum + a * b)
+                }
+                _ => (enabled, sum),
+            }
+        });
+
+    Ok(sum)
+}
+
+utils::example_tests!(Day03);
@@ -0,0 +1,175 @@
+use std::collections::HashSet;
+use tracing::Level;
+use utils::{
+    geom::{self, ray, DenseGrid, Direction8},
+    prelude::*,
+};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = LetterGrid;
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str, _: &Self::Params) -> Result<Self::Input> {
+        LetterGrid::new(input)
+    }
+
+    fn part_one(grid: &Self::Input) -> Result<usize> {
+        part_one(grid)
+    }
+
+    fn part_two(grid: &Self::Input) -> Result<usize> {
+        part_two(grid)
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example::new("example", include_str!("../input/example.txt"))
+                .part_one("18")
+                .part_two("9"),
+        ]
+    }
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(grid))]
+fn part_one(grid: &LetterGrid) -> Result<usize> {
+    let word: [char; 4] = ['X', 'M', 'A', 'S'];
+    Ok(grid.count_word(&word))
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(grid))]
+fn part_two(grid: &LetterGrid) -> Result<usize> {
+    Ok(grid.count_crosses())
+}
+
+#[allow(unused)]
+fn print_xmas(grid: &LetterGrid, word: &[char; 4]) {
+    let positions = grid.find_word::<Vec<_>>(word);
+
+    let check: HashSet<_> = positions.iter().flatten().collect();
+
+    for (y, row) in grid.letters.rows().enumerate() {
+        for (x, c) in row.iter().enumerate() {
+            if check.contains(&Point::new(x as i32, y as i32)) {
+                print!("{}", c);
+            } else {
+                print!(".");
+            }
+        }
+        println!();
+    }
+}
+
+type Point = geom::Point<i32>;
+
+pub struct LetterGrid {
+    letters: DenseGrid<i32, char>,
+}
+
+impl LetterGrid {
+    fn new(input: &str) -> Result<Self> {
+        Ok(Self {
+            letters: input.parse()?,
+        })
+    }
+
+    fn check_word(&self, start: Point, direction: Direction8, word: &[char]) -> bool {
+        ray(start, direction, word.len())
+            .zip(word)
+            .all(|(p, c)| self.letters.get(&p) == Some(c))
+    }
+
+    fn check_mas_cross(&self, position: &Point) -> bool {
+        let letter = |direction| self.letters.get(&position.step8(&direction));
+
+        self.letters.get(position) == Some(&'A')
+            && [
+                (Direction8::NW, Direction8::SE),
+                (Direction8::NE, Direction8::SW),
+            ]
+            .iter()
+            .all(|&(a, b)| {
+                matches!(
+                    (letter(a), letter(b)),
+                    (Some('M'), Some('S')) | (Some('S'), Some('M'))
+                )
+            })
+    }
+
+    fn count_crosses(&self) -> usize {
+        self.letters
+            .iter()
+            .filter(|(pos, _)| self.check_mas_cross(pos))
+            .count()
+    }
+
+    /// The positions of every occurrence of `word`, in any of the eight
+    /// directions.
+    #[allow(unused)]
+    fn find_word<C>(&self, word: &[char]) -> C
+    where
+        C: FromIterator<Vec<Point>>,
+    {
+        self.letters
+            .iter()
+            .flat_map(|(pos, _)| {
+                Direction8::iter()
+                    .filter(move |&d| self.check_word(pos, d, word))
+                    .map(move |d| ray(pos, d, word.len()).collect())
+            })
+            .collect()
+    }
+
+    fn count_word(&self, word: &[char]) -> usize {
+        self.letters
+            .iter()
+            .map(|(pos, _)| {
+                Direction8::iter()
+                    .filter(|&d| self.check_word(pos, d, word))
+                    .count()
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const XMAS: [char; 4] = ['X', 'M', 'A', 'S'];
+
+    #[test]
+    fn test_part_one() {
+        let grid = LetterGrid::new("XMAS\nM..A\nA..M\nSAMX").unwrap();
+        assert_eq!(grid.count_word(&XMAS), 4);
+    }
+
+    #[test]
+    fn test_find_word() {
+        let grid = LetterGrid::new("XMAS\nMM.A\nA.AM\nSAMS").unwrap();
+
+        let found: HashSet<_> = grid.find_word(&XMAS);
+        assert_eq!(
+            found,
+            HashSet::from([
+                ray(Point::new(0, 0), Direction8::E, 4).collect::<Vec<_>>(),
+                ray(Point::new(0, 0), Direction8::SE, 4).collect::<Vec<_>>(),
+                ray(Point::new(0, 0), Direction8::S, 4).collect::<Vec<_>>(),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_check_cross() {
+        let grid = LetterGrid::new("M.M\n.A.\nS.S").unwrap();
+        assert!(grid.check_mas_cross(&Point::new(1, 1)));
+        assert!(!grid.check_mas_cross(&Point::new(1, 2)));
+    }
+}
+
+utils::example_tests!(Day04);
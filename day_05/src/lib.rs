@@ -0,0 +1,124 @@
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use std::result::Result as StdResult;
+use std::str::FromStr;
+use tracing::Level;
+use utils::{parse, prelude::*};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = (RuleSet, Vec<Update>);
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str, _: &Self::Params) -> Result<Self::Input> {
+        let [rules, updates] = parse::sections_array(input)?;
+        let rules: RuleSet = rules.parse_lines(|l| parse::ints_array(l).map(|[a, b]| (a, b)))?;
+        let updates: Vec<Update> = updates.parse_lines(str::parse)?;
+
+        Ok((rules, updates))
+    }
+
+    fn part_one((rules, updates): &Self::Input) -> Result<usize> {
+        part_one(rules, updates)
+    }
+
+    fn part_two((rules, updates): &Self::Input) -> Result<usize> {
+        part_two(rules, updates)
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example::new("example", include_str!("../input/example.txt"))
+                .part_one("143")
+                .part_two("123"),
+        ]
+    }
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(rules,updates))]
+fn part_one(rules: &RuleSet, updates: &[Update]) -> Result<usize> {
+    Ok(updates
+        .iter()
+        .filter_map(|u| {
+            if u.is_valid(rules) {
+                Some(u.get_middle())
+            } else {
+                None
+            }
+        })
+        .sum())
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(rules,updates))]
+fn part_two(rules: &RuleSet, updates: &[Update]) -> Result<usize> {
+    updates
+        .iter()
+        .filter_map(|u| {
+            if u.is_valid(rules) {
+                None
+            } else {
+                // Find the element that has an equal number of elements in the before and after rules
+                Some(u.find_middle(rules).ok_or_else(|| {
+                    Error::SolutionNotFound(format!("did not find middle element in {u:?}"))
+                }))
+            }
+        })
+        .try_fold(0, |sum, e| e.map(|e| e + sum))
+}
+
+pub struct RuleSet(HashMap<usize, HashSet<usize>>);
+
+impl FromIterator<(usize, usize)> for RuleSet {
+    fn from_iter<T: IntoIterator<Item = (usize, usize)>>(iter: T) -> Self {
+        Self(iter.into_iter().into_grouping_map().collect())
+    }
+}
+impl RuleSet {
+    fn check_after(&self, first: &usize, second: &usize) -> bool {
+        self.0
+            .get(first)
+            .map(|after_first| after_first.contains(second))
+            .unwrap_or(false)
+    }
+}
+
+#[derive(Debug)]
+pub struct Update(Vec<usize>);
+
+impl FromStr for Update {
+    type Err = Error;
+
+    fn from_str(s: &str) -> StdResult<Self, Self::Err> {
+        Ok(Update(parse::list(s)?))
+    }
+}
+
+impl Update {
+    fn is_valid(&self, rules: &RuleSet) -> bool {
+        self.0.iter().combinations(2).all(|pair| {
+            let (a, b) = (pair[0], pair[1]);
+            rules.check_after(a, b)
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn get_middle(&self) -> &usize {
+        &self.0[self.len() / 2]
+    }
+
+    fn find_middle(&self, rules: &RuleSet) -> Option<&usize> {
+        self.0
+            .iter()
+            .find(|i| self.0.iter().filter(|j| rules.check_after(i, j)).count() == self.len() / 2)
+    }
+}
+
+utils::example_tests!(Day05);
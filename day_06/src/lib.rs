@@ -0,0 +1,161 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::iter;
+use std::result::Result as StdResult;
+use tracing::Level;
+use utils::geom::{DenseGrid, Direction};
+use utils::{cycle::brent, geom, prelude::*};
+
+#[macro_use]
+extern crate tramp;
+use tramp::{tramp, BorrowRec};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = (Map, (Direction, Point));
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str, _: &Self::Params) -> Result<Self::Input> {
+        let (map, markers) = Map::parse_with_markers(input, &['^'], Location::try_from)?;
+        let starting_point = (Direction::Up, geom::single_marker(&markers, '^')?);
+
+        Ok((map, starting_point))
+    }
+
+    fn part_one((map, starting_point): &Self::Input) -> Result<usize> {
+        part_one(map, *starting_point)
+    }
+
+    fn part_two((map, starting_point): &Self::Input) -> Result<usize> {
+        part_two(map, *starting_point)
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example::new("example", include_str!("../input/example.txt"))
+                .part_one("41")
+                .part_two("6"),
+        ]
+    }
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(map, starting_point))]
+fn part_one(map: &Map, starting_point: (Direction, Point)) -> Result<usize> {
+    fn take_step(
+        map: &Map,
+        position: Point,
+        direction: Direction,
+        mut visited: HashSet<Point>,
+    ) -> BorrowRec<'_, usize> {
+        let next_position = position.step(&direction);
+
+        match map.get(&next_position) {
+            Some(Location::Obstacle) => {
+                tracing::debug!("obstacle at {:?}, turning right", next_position);
+                let direction = direction.rotate_right();
+                rec_call!(take_step(map, position, direction, visited))
+            }
+            Some(Location::Empty) => {
+                visited.insert(next_position);
+                tracing::debug!("moving to {:?}, now visited {:?}", next_position, visited);
+                rec_call!(take_step(map, next_position, direction, visited))
+            }
+            None => {
+                tracing::debug!("reached end of map, visited {:?}", visited);
+                rec_ret!(visited.len())
+            }
+        }
+    }
+
+    let (direction, position) = starting_point;
+    let mut visited = HashSet::new();
+    visited.insert(position);
+    Ok(tramp(take_step(map, position, direction, visited)))
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(map,starting_point))]
+fn part_two(map: &Map, starting_point: (Direction, Point)) -> Result<usize> {
+    let path: HashSet<Point> =
+        iter::successors(Some(starting_point), |state| patrol_step(map, None, state))
+            .map(|(_, position)| position)
+            .collect();
+
+    let loops = path
+        .iter()
+        .filter(|&&candidate| candidate != starting_point.1)
+        .filter(|&&candidate| {
+            let cycle = brent(Some(starting_point), |state| {
+                state.and_then(|state| patrol_step(map, Some(candidate), &state))
+            });
+            let loops = cycle.state.is_some();
+            if loops {
+                tracing::info!("inserting 'O' at {:?}", candidate);
+            }
+            loops
+        })
+        .count();
+
+    Ok(loops)
+}
+
+/// A single move of the guard, with an optional additional obstacle. Returns
+/// `None` once the guard leaves the map, which is a fixed point when the guard
+/// is seen as a cyclic simulation over `Option` states.
+fn patrol_step(
+    map: &Map,
+    obstacle: Option<Point>,
+    &(direction, position): &(Direction, Point),
+) -> Option<(Direction, Point)> {
+    let next_position = position.step(&direction);
+
+    match map.get(&next_position)? {
+        Location::Obstacle => Some((direction.rotate_right(), position)),
+        Location::Empty if Some(next_position) == obstacle => {
+            Some((direction.rotate_right(), position))
+        }
+        Location::Empty => Some((direction, next_position)),
+    }
+}
+
+type Map = DenseGrid<i32, Location>;
+
+type Point = geom::Point<i32>;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Location {
+    #[default]
+    Empty,
+    Obstacle,
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Location::Empty => write!(f, "."),
+            Location::Obstacle => write!(f, "#"),
+        }
+    }
+}
+
+impl TryFrom<char> for Location {
+    type Error = Error;
+
+    fn try_from(c: char) -> StdResult<Self, Self::Error> {
+        match c {
+            '.' => Ok(Location::Empty),
+            '#' => Ok(Location::Obstacle),
+            _ => Err(parse_error(
+                "could not parse location",
+                &format!("invalid character: {}", c),
+            )),
+        }
+    }
+}
+
+utils::example_tests!(Day06);
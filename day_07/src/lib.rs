@@ -0,0 +1,158 @@
+use itertools::Itertools;
+use std::fmt::Display;
+use std::result::Result as StdResult;
+use std::str::FromStr;
+use tracing::Level;
+use utils::{parse, prelude::*, scan};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<Equation>;
+    type Params = ();
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str, _: &Self::Params) -> Result<Self::Input> {
+        parse::lines(input, str::parse)
+    }
+
+    fn part_one(equations: &Self::Input) -> Result<u64> {
+        part_one(equations)
+    }
+
+    fn part_two(equations: &Self::Input) -> Result<u64> {
+        part_two(equations)
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example::new("example", include_str!("../input/example.txt"))
+                .part_one("3749")
+                .part_two("11387"),
+        ]
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Equation(u64, Vec<u64>);
+
+impl Display for Equation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.0, self.1.iter().join(" "))
+    }
+}
+
+impl FromStr for Equation {
+    type Err = Error;
+
+    fn from_str(s: &str) -> StdResult<Self, Self::Err> {
+        let (left, right) = scan!(s, "{}: {}" => u64, String)?;
+        Ok(Self(left, parse::list(&right)?))
+    }
+}
+
+impl Equation {
+    fn resolve(&self, signs: &[Operator]) -> Option<Vec<Operator>> {
+        fn inner(
+            eq: &Equation,
+            i: usize,
+            acc: u64,
+            signs: Vec<Operator>,
+            possible_signs: &[Operator],
+        ) -> Option<Vec<Operator>> {
+            if i == eq.1.len() {
+                if acc == eq.0 {
+                    Some(signs)
+                } else {
+                    None
+                }
+            } else {
+                possible_signs.iter().find_map(|&sign| {
+                    let next = sign.apply(acc, eq.1[i]);
+
+                    if next > eq.0 {
+                        None
+                    } else {
+                        let mut signs = signs.clone();
+                        signs.push(sign);
+                        inner(eq, i + 1, next, signs, possible_signs)
+                    }
+                })
+            }
+        }
+
+        inner(self, 0, 0, Vec::new(), signs)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operator {
+    Plus,
+    Times,
+    Concat,
+}
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Plus => write!(f, "+"),
+            Self::Times => write!(f, "*"),
+            Self::Concat => write!(f, "||"),
+        }
+    }
+}
+
+impl Operator {
+    const MATH: [Self; 2] = [Self::Plus, Self::Times];
+
+    const ALL: [Self; 3] = [Self::Plus, Self::Times, Self::Concat];
+
+    fn apply(&self, a: u64, b: u64) -> u64 {
+        match self {
+            Self::Plus => a + b,
+            Self::Times => a * b,
+            Self::Concat => a * 10u64.pow(b.ilog10() + 1) + b,
+        }
+    }
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(equations))]
+fn part_one(equations: &[Equation]) -> Result<u64> {
+    Ok(equations
+        .iter()
+        .filter_map(|e| e.resolve(&Operator::MATH).map(|_| e.0))
+        .sum())
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(equations))]
+fn part_two(equations: &[Equation]) -> Result<u64> {
+    Ok(equations
+        .iter()
+        .filter_map(|e| e.resolve(&Operator::ALL).map(|_| e.0))
+        .sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operators() {
+        assert_eq!(Operator::Plus.apply(1, 2), 3);
+        assert_eq!(Operator::Plus.apply(100, 2), 102);
+
+        assert_eq!(Operator::Times.apply(1, 2), 2);
+        assert_eq!(Operator::Times.apply(8, 4), 32);
+
+        assert_eq!(Operator::Concat.apply(1, 2), 12);
+        assert_eq!(Operator::Concat.apply(12, 34), 1234);
+        assert_eq!(Operator::Concat.apply(100, 100), 100100);
+        assert_eq!(Operator::Concat.apply(10, 20), 1020);
+        assert_eq!(Operator::Concat.apply(1, 10), 110);
+    }
+}
+
+utils::example_tests!(Day07);
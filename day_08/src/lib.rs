@@ -0,0 +1,100 @@
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use tracing::Level;
+use utils::geom::BBox;
+use utils::geom::Point;
+use utils::prelude::*;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = (HashMap<char, Vec<Point<i32>>>, BBox<i32>);
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str, _: &Self::Params) -> Result<Self::Input> {
+        let (max_x, max_y, stations) = input.lines().enumerate().fold(
+            (0, 0, Vec::<(char, Point<i32>)>::new()),
+            |(max_x, max_y, stations), (y, line)| {
+                let (max_x, stations) = line.chars().enumerate().fold(
+                    (max_x, stations),
+                    |(max_x, mut stations), (x, c)| {
+                        if c != '.' {
+                            stations.push((c, Point::new(x as i32, y as i32)));
+                        }
+                        (max_x.max(x), stations)
+                    },
+                );
+
+                (max_x, max_y.max(y), stations)
+            },
+        );
+
+        let stations: HashMap<char, Vec<Point<i32>>> = stations.into_iter().into_group_map();
+        let bounds = BBox::new(0, max_x as i32, 0, max_y as i32);
+
+        Ok((stations, bounds))
+    }
+
+    fn part_one((stations, bounds): &Self::Input) -> Result<usize> {
+        part_one(stations, bounds)
+    }
+
+    fn part_two((stations, bounds): &Self::Input) -> Result<usize> {
+        part_two(stations, bounds)
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example::new("example", include_str!("../input/example.txt"))
+                .part_one("14")
+                .part_two("34"),
+        ]
+    }
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(stations, bbox))]
+fn part_one(stations: &HashMap<char, Vec<Point<i32>>>, bbox: &BBox<i32>) -> Result<usize> {
+    let points: HashSet<_> = stations
+        .values()
+        .flat_map(|points| {
+            points
+                .iter()
+                .combinations(2)
+                .filter_map(|a| a.into_iter().collect_tuple())
+                .flat_map(|(a, b)| [(a, b), (b, a)])
+                .filter_map(|(a, b)| {
+                    let p = b.move_by(b - a);
+                    bbox.filter(p)
+                })
+                .collect::<HashSet<_>>()
+        })
+        .collect();
+
+    Ok(points.len())
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(stations, bbox))]
+fn part_two(stations: &HashMap<char, Vec<Point<i32>>>, bbox: &BBox<i32>) -> Result<usize> {
+    let points: HashSet<Point<i32>> = stations
+        .values()
+        .flat_map(|points| {
+            points
+                .iter()
+                .combinations(2)
+                .filter_map(|a| a.into_iter().collect_tuple())
+                .flat_map(|(a, b)| [(a, b), (b, a)])
+                .flat_map(|(&a, &b)| {
+                    std::iter::successors(Some(b), move |p| bbox.filter(p.move_by(b - a)))
+                })
+                .collect::<HashSet<_>>()
+        })
+        .collect();
+
+    Ok(points.len())
+}
+
+utils::example_tests!(Day08);
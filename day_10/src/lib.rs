@@ -0,0 +1,91 @@
+use itertools::Itertools;
+use tracing::Level;
+use utils::{geom, memo::Memo, prelude::*};
+
+type Map = geom::DenseGrid<i16, u8>;
+
+type Point = geom::Point<i16>;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Map;
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str, _: &Self::Params) -> Result<Self::Input> {
+        Map::parse_with(input, |c| {
+            c.to_digit(10)
+                .map(|height| height as u8)
+                .ok_or_else(|| parse_error("could not parse digit", &c.to_string()))
+        })
+    }
+
+    fn part_one(map: &Self::Input) -> Result<usize> {
+        part_one(map)
+    }
+
+    fn part_two(map: &Self::Input) -> Result<u64> {
+        part_two(map)
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example::new("example", include_str!("../input/example.txt"))
+                .part_one("36")
+                .part_two("81"),
+        ]
+    }
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(map))]
+fn part_one(map: &Map) -> Result<usize> {
+    let trailheads = map
+        .iter()
+        .filter(|(_, &height)| height == 0)
+        .map(|(point, _)| point)
+        .collect_vec();
+
+    Ok(trailheads
+        .into_iter()
+        .map(|start| {
+            (1..=9)
+                .fold(im::HashSet::unit(start), |trails, height| {
+                    trails
+                        .into_iter()
+                        .flat_map(|t| {
+                            map.neighbors(&t)
+                                .filter_map(|(p, &h)| if h == height { Some(p) } else { None })
+                                .collect_vec()
+                        })
+                        .collect()
+                })
+                .len()
+        })
+        .sum())
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(map))]
+fn part_two(map: &Map) -> Result<u64> {
+    let trailheads = map
+        .iter()
+        .filter_map(|(p, &h)| if h == 0 { Some(p) } else { None })
+        .collect_vec();
+    let mut memo = Memo::new("rating");
+    let rating = |rating: &mut dyn FnMut(Point) -> usize, p: &Point| match map.get(p) {
+        Some(&9) => 1,
+        Some(&h) => map
+            .neighbors(p)
+            .filter(|&(_, &nh)| h + 1 == nh)
+            .map(|(np, _)| rating(np))
+            .sum(),
+        None => 0,
+    };
+    let r: usize = trailheads.into_iter().map(|t| memo.get(t, &rating)).sum();
+    Ok(r as u64)
+}
+
+utils::example_tests!(Day10);
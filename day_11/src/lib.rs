@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use tracing::Level;
+use utils::{parse, prelude::*};
+
+utils::params! {
+    #[derive(Debug, Clone)]
+    pub struct Options {
+        /// The number of blinks in part one.
+        blinks_one: usize = 25;
+        /// The number of blinks in part two.
+        blinks_two: usize = 75;
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = (Vec<u64>, Options);
+    type Params = Options;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str, options: &Options) -> Result<Self::Input> {
+        let stones: Vec<Vec<u64>> = parse::lines(input, parse::list)?;
+        Ok((stones.concat(), options.clone()))
+    }
+
+    fn part_one((stones, options): &Self::Input) -> Result<usize> {
+        part_one(stones, options.blinks_one)
+    }
+
+    fn part_two((stones, options): &Self::Input) -> Result<usize> {
+        part_two(stones, options.blinks_two)
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![Example::new("example", include_str!("../input/example.txt")).part_one("55312")]
+    }
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(stones))]
+fn part_one(stones: &[u64], blinks: usize) -> Result<usize> {
+    Ok(simulate(stones, blinks))
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(stones))]
+fn part_two(stones: &[u64], blinks: usize) -> Result<usize> {
+    Ok(simulate(stones, blinks))
+}
+
+fn simulate(stones: &[u64], iterations: usize) -> usize {
+    let stones = stones.iter().map(|&s| (s, 1)).into_grouping_map().sum();
+
+    (0..iterations)
+        .fold(stones, |stones, _| {
+            let new_stones = HashMap::new();
+            stones
+                .into_iter()
+                .fold(new_stones, |mut new_stones, (stone, count)| {
+                    if stone == 0 {
+                        insert(&mut new_stones, 1, count);
+                        new_stones
+                    } else if nb_of_digits(&stone).is_multiple_of(2) {
+                        let (first, second) = split(&stone);
+                        insert(&mut new_stones, first, count);
+                        insert(&mut new_stones, second, count);
+                        new_stones
+                    } else {
+                        insert(&mut new_stones, stone * 2024, count);
+                        new_stones
+                    }
+                })
+        })
+        .values()
+        .sum()
+}
+
+fn insert(stones: &mut HashMap<u64, usize>, stone: u64, count: usize) {
+    let existing = stones.entry(stone).or_insert(0);
+    *existing += count;
+}
+
+fn nb_of_digits(n: &u64) -> u32 {
+    if n < &10 {
+        1
+    } else {
+        n.ilog10() + 1
+    }
+}
+
+fn split(n: &u64) -> (u64, u64) {
+    let half = nb_of_digits(n) / 2;
+    let first = n / 10u64.pow(half);
+    let second = n % 10u64.pow(half);
+    (first, second)
+}
+
+utils::example_tests!(Day11);
@@ -0,0 +1,266 @@
+use tracing::Level;
+use utils::{
+    geom::{components, DenseGrid, Region},
+    prelude::*,
+};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Garden;
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str, _: &Self::Params) -> Result<Self::Input> {
+        let plots: DenseGrid<i32, char> = input.parse()?;
+        let garden = Garden(components(plots.iter().map(|(p, &plant)| (p, plant))));
+
+        tracing::debug!("constructed garden with {} regions", garden.iter().count());
+
+        Ok(garden)
+    }
+
+    fn part_one(garden: &Self::Input) -> Result<usize> {
+        part_one(garden)
+    }
+
+    fn part_two(garden: &Self::Input) -> Result<u64> {
+        part_two(garden)
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example::new("example", include_str!("../input/example.txt"))
+                .part_one("140")
+                .part_two("80"),
+            Example::new("example_2", include_str!("../input/example_2.txt"))
+                .part_one("772")
+                .part_two("436"),
+            Example::new("example_3", include_str!("../input/example_3.txt"))
+                .part_one("1930")
+                .part_two("1206"),
+            Example::new("example_4", include_str!("../input/example_4.txt")).part_two("236"),
+            Example::new("example_5", include_str!("../input/example_5.txt")).part_two("368"),
+        ]
+    }
+}
+
+pub struct Garden(Vec<(char, Region<i32>)>);
+
+impl Garden {
+    fn iter(&self) -> impl Iterator<Item = &(char, Region<i32>)> {
+        self.0.iter()
+    }
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(garden))]
+fn part_one(garden: &Garden) -> Result<usize> {
+    let price = garden
+        .iter()
+        .map(|(c, region)| {
+            let area = region.area();
+            let perimeter = region.perimeter();
+
+            let price = area * perimeter;
+            tracing::debug!(
+                "region {} with area {} and perimeter {}: {}",
+                c,
+                area,
+                perimeter,
+                price
+            );
+            price
+        })
+        .sum();
+
+    Ok(price)
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(garden))]
+fn part_two(garden: &Garden) -> Result<u64> {
+    let price = garden
+        .iter()
+        .map(|(c, region)| {
+            let area = region.area();
+            let edges = region.sides();
+
+            let price = area as u64 * edges as u64;
+
+            tracing::debug!(
+                "region {} with area {} and edges {}: {}",
+                c,
+                area,
+                edges,
+                price
+            );
+            price
+        })
+        .sum();
+
+    Ok(price)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use utils::geom::Point;
+
+    fn corners(point: &Point<i32>, points: &HashSet<Point<i32>>) -> usize {
+        points
+            .iter()
+            .copied()
+            .collect::<Region<_>>()
+            .corners_at(point)
+    }
+
+    fn edges(points: &HashSet<Point<i32>>) -> usize {
+        points.iter().copied().collect::<Region<_>>().sides()
+    }
+
+    #[test]
+    fn test_corners() {
+        assert_eq!(
+            corners(&Point::new(0, 0), &[Point::new(0, 0)].into_iter().collect()),
+            4
+        );
+
+        assert_eq!(
+            corners(
+                &Point::new(0, 0),
+                &[Point::new(0, 0), Point::new(1, 0)].into_iter().collect()
+            ),
+            2
+        );
+        assert_eq!(
+            corners(
+                &Point::new(0, 0),
+                &[Point::new(0, 0), Point::new(0, 1)].into_iter().collect()
+            ),
+            2
+        );
+
+        assert_eq!(
+            corners(
+                &Point::new(0, 0),
+                &[Point::new(1, 0), Point::new(0, 0), Point::new(0, 1)]
+                    .into_iter()
+                    .collect()
+            ),
+            2
+        );
+
+        assert_eq!(
+            corners(
+                &Point::new(0, 0),
+                &[Point::new(0, 0), Point::new(0, 1), Point::new(0, -1)]
+                    .into_iter()
+                    .collect()
+            ),
+            0
+        );
+    }
+
+    #[test]
+    fn test_edges() {
+        assert_eq!(edges(&[Point::new(0, 0)].into_iter().collect()), 4);
+        assert_eq!(
+            edges(&[Point::new(0, 0), Point::new(1, 0)].into_iter().collect()),
+            4
+        );
+        assert_eq!(
+            edges(
+                &[
+                    Point::new(0, 0),
+                    Point::new(1, 0),
+                    Point::new(1, 1),
+                    Point::new(0, 1)
+                ]
+                .into_iter()
+                .collect()
+            ),
+            4
+        );
+
+        assert_eq!(
+            edges(
+                &[Point::new(0, 0), Point::new(1, 0), Point::new(0, 1)]
+                    .into_iter()
+                    .collect()
+            ),
+            6
+        );
+
+        assert_eq!(
+            edges(
+                &[
+                    Point::new(0, 0),
+                    Point::new(0, 1),
+                    Point::new(1, 1),
+                    Point::new(2, 1),
+                    Point::new(2, 0)
+                ]
+                .into_iter()
+                .collect()
+            ),
+            8
+        );
+        assert_eq!(
+            edges(
+                &[
+                    Point::new(0, 0),
+                    Point::new(0, 1),
+                    Point::new(0, 2),
+                    Point::new(1, 2),
+                    Point::new(2, 2),
+                    Point::new(2, 1),
+                    Point::new(2, 0)
+                ]
+                .into_iter()
+                .collect()
+            ),
+            8
+        );
+
+        assert_eq!(
+            edges(
+                &[
+                    Point::new(0, 0),
+                    Point::new(0, 1),
+                    Point::new(0, 2),
+                    Point::new(1, 2),
+                    Point::new(2, 2),
+                    Point::new(2, 1),
+                    Point::new(2, 0),
+                    Point::new(1, 0)
+                ]
+                .into_iter()
+                .collect()
+            ),
+            8
+        );
+        assert_eq!(
+            edges(
+                &[
+                    Point::new(0, 0),
+                    Point::new(0, 1),
+                    Point::new(0, 2),
+                    Point::new(1, 2),
+                    Point::new(2, 2),
+                    Point::new(2, 1),
+                    Point::new(2, 0),
+                    Point::new(1, 0),
+                    Point::new(1, 1)
+                ]
+                .into_iter()
+                .collect()
+            ),
+            4
+        );
+    }
+}
+
+utils::example_tests!(Day12);
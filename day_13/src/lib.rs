@@ -0,0 +1,140 @@
+use itertools::Itertools;
+use tracing::Level;
+use utils::{parse, prelude::*, scan};
+
+type Button = utils::geom::Vector<i64>;
+type Prize = utils::geom::Point<i64>;
+
+utils::params! {
+    #[derive(Debug, Clone)]
+    pub struct Options {
+        /// The distance the prizes are moved away in part two.
+        offset: i64 = 10000000000000;
+    }
+}
+
+/// The templates of the lines describing a claw machine, in order.
+const TEMPLATES: [&str; 3] = [
+    "Button A: X+{}, Y+{}",
+    "Button B: X+{}, Y+{}",
+    "Prize: X={}, Y={}",
+];
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = (Vec<Arcade>, Options);
+    type Params = Options;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str, options: &Options) -> Result<Self::Input> {
+        parse::sections(input)
+            .into_iter()
+            .map(|section| {
+                let mut templates = TEMPLATES.into_iter();
+                let values: Vec<(i64, i64)> = section.parse_lines(|line| {
+                    let template = templates
+                        .next()
+                        .ok_or_else(|| parse_error("expected two buttons and a prize", line))?;
+                    scan!(line, template => i64, i64)
+                })?;
+                let [(ax, ay), (bx, by), (px, py)] = values.try_into().map_err(|_| {
+                    let (y, line) = section.lines().last().unwrap_or((section.start, ""));
+                    parse_error("expected two buttons and a prize", line).in_line(y, line)
+                })?;
+
+                Ok::<_, Error>(Arcade {
+                    a: Button::new(ax, ay),
+                    b: Button::new(bx, by),
+                    prize: Prize::new(px, py),
+                })
+            })
+            .try_collect()
+            .map(|arcades| (arcades, options.clone()))
+    }
+
+    fn part_one((arcades, _): &Self::Input) -> Result<u64> {
+        part_one(arcades)
+    }
+
+    fn part_two((arcades, options): &Self::Input) -> Result<u64> {
+        part_two(arcades, options.offset)
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![Example::new("example", include_str!("../input/example.txt")).part_one("480")]
+    }
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(arcades))]
+fn part_one(arcades: &[Arcade]) -> Result<u64> {
+    Ok(arcades
+        .iter()
+        .filter_map(|a| a.solve())
+        .map(|(na, nb)| na * 3 + nb)
+        .sum())
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(arcades))]
+fn part_two(arcades: &[Arcade], offset: i64) -> Result<u64> {
+    Ok(arcades
+        .iter()
+        .cloned()
+        .filter_map(|a| a.supercharge(offset).solve())
+        .map(|(na, nb)| na * 3 + nb)
+        .sum())
+}
+
+#[derive(Debug, Clone)]
+pub struct Arcade {
+    a: Button,
+    b: Button,
+    prize: Prize,
+}
+
+impl Arcade {
+    fn supercharge(self, offset: i64) -> Self {
+        Arcade {
+            prize: Prize::new(self.prize.x + offset, self.prize.y + offset),
+            ..self
+        }
+    }
+
+    fn solve(&self) -> Option<(u64, u64)> {
+        let ax = self.a.dx as f64;
+        let ay = self.a.dy as f64;
+        let bx = self.b.dx as f64;
+        let by = self.b.dy as f64;
+        let px = self.prize.x as f64;
+        let py = self.prize.y as f64;
+
+        let b = (py / by - (ay * px) / (by * ax)) / (1f64 - (ay * bx) / (by * ax));
+        if b < 0f64 {
+            return None;
+        }
+
+        tracing::debug!("b = {}", b);
+        let a = (px - bx * b) / ax;
+        tracing::debug!("a = {}", a);
+
+        if a < 0f64 {
+            return None;
+        }
+
+        let a = a.round() as u64;
+        let b = b.round() as u64;
+
+        if a as i64 * self.a.dx + b as i64 * self.b.dx == self.prize.x
+            && a as i64 * self.a.dy + b as i64 * self.b.dy == self.prize.y
+        {
+            Some((a, b))
+        } else {
+            None
+        }
+    }
+}
+
+utils::example_tests!(Day13);
@@ -0,0 +1,190 @@
+use std::{collections::HashSet, str::FromStr};
+
+use itertools::Itertools;
+use tracing::Level;
+use utils::{
+    cycle::brent,
+    geom::{BBox, Point, Vector},
+    parse,
+    prelude::*,
+    scan,
+};
+
+utils::params! {
+    #[derive(Debug, Clone)]
+    pub struct Options {
+        width: i16 = 101, example 11;
+        height: i16 = 103, example 7;
+        /// The first second considered when looking for the christmas tree.
+        start: u32 = 0;
+    }
+}
+
+impl Options {
+    fn boundaries(&self) -> (i16, i16) {
+        (self.width, self.height)
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = (Vec<Robot>, Options);
+    type Params = Options;
+    type Answer1 = usize;
+    type Answer2 = u32;
+
+    fn parse(input: &str, options: &Options) -> Result<Self::Input> {
+        let robots = parse::lines(input, str::parse)?;
+        Ok((robots, options.clone()))
+    }
+
+    fn part_one((robots, options): &Self::Input) -> Result<usize> {
+        part_one(robots, options.boundaries())
+    }
+
+    fn part_two((robots, options): &Self::Input) -> Result<u32> {
+        part_two(robots, options.boundaries(), options.start)
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![Example::new("example", include_str!("../input/example.txt")).part_one("12")]
+    }
+}
+
+pub struct Robot {
+    p: Point<i16>,
+    v: Vector<i16>,
+}
+
+impl Robot {
+    /// The position one second after being at `p`.
+    fn advance(&self, p: &Point<i16>, boundaries: (i16, i16)) -> Point<i16> {
+        Point::new(
+            (p.x + self.v.dx).rem_euclid(boundaries.0),
+            (p.y + self.v.dy).rem_euclid(boundaries.1),
+        )
+    }
+
+    fn simulate(&self, t: u32, boundaries: (i16, i16)) -> Point<i16> {
+        let x =
+            (self.p.x as i64 + self.v.dx as i64 * t as i64).rem_euclid(boundaries.0 as i64) as i16;
+        let y =
+            (self.p.y as i64 + self.v.dy as i64 * t as i64).rem_euclid(boundaries.1 as i64) as i16;
+
+        Point::new(x, y)
+    }
+}
+
+impl FromStr for Robot {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (x, y, vx, vy) = scan!(s, "p={},{} v={},{}" => i16, i16, i16, i16)?;
+
+        Ok(Robot {
+            p: Point::new(x, y),
+            v: Vector::new(vx, vy),
+        })
+    }
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(robots))]
+fn part_one(robots: &[Robot], boundaries: (i16, i16)) -> Result<usize> {
+    let positions = robots
+        .iter()
+        .map(|r| r.simulate(100, boundaries))
+        .collect_vec();
+
+    let quadrants = area(boundaries)
+        .quadrants()
+        .ok_or_else(|| Error::SolutionNotFound("the room has no quadrants".to_owned()))?
+        .map(|quadrant| positions.iter().filter(|p| quadrant.contains(p)).count());
+
+    tracing::info!("Robots per quadrant: {:?}", quadrants);
+
+    Ok(quadrants.iter().product())
+}
+
+/// Looks for the first second, from `start` on, at which no two robots share
+/// a tile. The puzzle only asks for the picture of a christmas tree, so this is
+/// a heuristic that happens to hold for the inputs rather than its definition;
+/// render the positions at debug level to confirm the answer. The robots return
+/// to their initial positions after a full cycle, so only one cycle has to be
+/// searched.
+#[tracing::instrument(level=Level::DEBUG,skip(robots))]
+fn part_two(robots: &[Robot], boundaries: (i16, i16), start: u32) -> Result<u32> {
+    let initial = robots.iter().map(|r| r.p).collect_vec();
+    let cycle = brent(initial, |positions| {
+        positions
+            .iter()
+            .zip(robots)
+            .map(|(p, r)| r.advance(p, boundaries))
+            .collect_vec()
+    });
+    tracing::info!("positions repeat every {} seconds", cycle.length);
+
+    let t = (start..)
+        .take(cycle.length)
+        .find(|&t| {
+            let positions = robots
+                .iter()
+                .map(|r| r.simulate(t, boundaries))
+                .collect::<HashSet<_>>();
+            positions.len() == robots.len()
+        })
+        .ok_or_else(|| Error::SolutionNotFound("Could not find the solution".to_string()))?;
+
+    if tracing::enabled!(Level::DEBUG) {
+        let positions = robots.iter().map(|r| r.simulate(t, boundaries)).collect();
+        tracing::debug!("after {} seconds:\n{}", t, render(&positions, boundaries));
+    }
+
+    Ok(cycle.reduce(t as usize) as u32)
+}
+
+fn render(positions: &HashSet<Point<i16>>, boundaries: (i16, i16)) -> String {
+    area(boundaries).render(|p| if positions.contains(p) { '#' } else { '.' })
+}
+
+fn area((width, height): (i16, i16)) -> BBox<i16> {
+    BBox::new(0, width - 1, 0, height - 1)
+}
+
+#[cfg(test)]
+mod test {
+
+    #[test]
+    fn test_simulate() {
+        let r = super::Robot {
+            p: utils::geom::Point::new(2, 4),
+            v: utils::geom::Vector::new(2, -3),
+        };
+
+        let boundaries = (11, 7);
+
+        assert_eq!((-2_i32).rem_euclid(7), 5);
+
+        let p = r.simulate(0, boundaries);
+        assert_eq!(p, utils::geom::Point::new(2, 4));
+
+        let p = r.simulate(1, boundaries);
+        assert_eq!(p, utils::geom::Point::new(4, 1));
+
+        let p = r.simulate(2, boundaries);
+        assert_eq!(p, utils::geom::Point::new(6, 5));
+
+        let p = r.simulate(3, boundaries);
+        assert_eq!(p, utils::geom::Point::new(8, 2));
+
+        let p = r.simulate(4, boundaries);
+        assert_eq!(p, utils::geom::Point::new(10, 6));
+
+        let p = r.simulate(5, boundaries);
+        assert_eq!(p, utils::geom::Point::new(1, 3));
+    }
+}
+
+utils::example_tests!(Day14);
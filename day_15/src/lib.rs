@@ -0,0 +1,221 @@
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+use tracing::Level;
+use utils::{
+    geom::{single_marker, Direction, Grid},
+    parse,
+    prelude::*,
+};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = (Point, Map, Vec<Direction>);
+    type Params = ();
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str, _: &Self::Params) -> Result<Self::Input> {
+        let [map, instructions] = parse::sections_array(input)?;
+
+        let (grid, markers) = Grid::parse_with_markers(map.text, &['@'], |c| match c {
+            '#' => Ok(Some(Element::Wall)),
+            'O' => Ok(Some(Element::Box)),
+            '.' => Ok(None),
+            _ => Err(parse_error("could not parse map", &c.to_string())),
+        })
+        .map_err(|e| map.locate(e))?;
+        let position = single_marker(&markers, '@')?;
+
+        let map = Map {
+            elements: grid.iter().map(|(p, e)| (*p, *e)).collect(),
+        };
+
+        let instuctions: Vec<Direction> = instructions
+            .lines()
+            .flat_map(|(y, l)| l.chars().enumerate().map(move |(x, c)| (y, x, c, l)))
+            .map(|(y, x, c, l)| match c {
+                '^' => Ok(Direction::Up),
+                'v' => Ok(Direction::Down),
+                '<' => Ok(Direction::Left),
+                '>' => Ok(Direction::Right),
+                _ => Err(parse_error_at("could not parse instruction", y, x, l)),
+            })
+            .try_collect()?;
+
+        Ok((position, map, instuctions))
+    }
+
+    fn part_one((position, map, instructions): &Self::Input) -> Result<u64> {
+        part_one(*position, map.clone(), instructions)
+    }
+
+    fn part_two((position, map, instructions): &Self::Input) -> Result<u64> {
+        part_two(*position, map.clone(), instructions)
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example::new("example", include_str!("../input/example.txt"))
+                .part_one("10092")
+                .part_two("9021"),
+            Example::new("example_2", include_str!("../input/example_2.txt")).part_one("2028"),
+        ]
+    }
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(position, map,instructions))]
+fn part_one(position: Point, map: Map, instructions: &[Direction]) -> Result<u64> {
+    let (_, map) = instructions.iter().fold((position, map), |(p, mut m), d| {
+        let next = p.step(d);
+        let p = m.move_to(next, d).unwrap_or(p);
+        (p, m)
+    });
+
+    Ok(map.gps_coordinates())
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(position, map,instructions))]
+fn part_two(position: Point, map: Map, instructions: &[Direction]) -> Result<u64> {
+    let wide = map.scale();
+    let position = Point::new(position.x * 2, position.y);
+
+    let (_, map) = instructions.iter().fold((position, wide), |(p, mut m), d| {
+        let p = m.w_move_to(p, d).unwrap_or(p);
+        (p, m)
+    });
+
+    Ok(map.gps_coordinates())
+}
+
+type Point = utils::geom::Point<i16>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Element {
+    Wall,
+    Box,
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    elements: HashMap<Point, Element>,
+}
+
+enum Lookup {
+    Wall,
+    Boxes(Vec<Point>),
+    Empty,
+}
+
+impl Map {
+    fn scale(self) -> Map {
+        let elements: HashMap<_, _> = self
+            .elements
+            .into_iter()
+            .flat_map(|(p, e)| {
+                let s = Point::new(p.x * 2, p.y);
+                match e {
+                    Element::Wall => vec![
+                        (s, Element::Wall),
+                        (s.step(&Direction::Right), Element::Wall),
+                    ],
+                    Element::Box => vec![(s, Element::Box)],
+                }
+            })
+            .collect();
+
+        Map { elements }
+    }
+
+    fn lookup(&self, p: &Point) -> Lookup {
+        match self.elements.get(p) {
+            None => match self.elements.get(&p.step(&Direction::Left)) {
+                Some(Element::Box) => Lookup::Boxes(vec![p.step(&Direction::Left), *p]),
+                _ => Lookup::Empty,
+            },
+            Some(Element::Wall) => Lookup::Wall,
+            Some(Element::Box) => Lookup::Boxes(vec![*p, p.step(&Direction::Right)]),
+        }
+    }
+
+    fn calculate(
+        &self,
+        mut to_move: Vec<Point>,
+        direction: &Direction,
+        mut moving: HashSet<Point>,
+    ) -> Option<HashSet<Point>> {
+        match to_move.pop() {
+            None => Some(moving),
+            Some(p) => {
+                let next = p.step(direction);
+                match self.lookup(&next) {
+                    Lookup::Wall => None,
+                    Lookup::Empty => {
+                        moving.insert(p);
+                        self.calculate(to_move, direction, moving)
+                    }
+                    Lookup::Boxes(boxes) => {
+                        let to_move = boxes.iter().fold(to_move, |mut a, p| {
+                            if !moving.contains(p) && !a.contains(p) {
+                                a.push(*p);
+                                a
+                            } else {
+                                a
+                            }
+                        });
+                        moving.insert(p);
+                        self.calculate(to_move, direction, moving)
+                    }
+                }
+            }
+        }
+    }
+
+    fn w_move_to(&mut self, p: Point, direction: &Direction) -> Option<Point> {
+        if let Some(points) = self.calculate(vec![p], direction, HashSet::new()) {
+            let new: Vec<(Point, Element)> = points
+                .iter()
+                .filter_map(|p| self.elements.remove(p).map(|e| (p.step(direction), e)))
+                .collect_vec();
+            for (p, e) in new {
+                self.elements.insert(p, e);
+            }
+            Some(p.step(direction))
+        } else {
+            None
+        }
+    }
+
+    fn move_to(&mut self, p: Point, d: &Direction) -> Option<Point> {
+        match self.elements.get(&p) {
+            None => Some(p),
+            Some(Element::Wall) => None,
+            Some(Element::Box) => {
+                let next = p.step(d);
+                match self.move_to(next, d) {
+                    None => None,
+                    Some(next) => {
+                        let e = self.elements.remove(&p).unwrap();
+                        self.elements.insert(next, e);
+                        Some(p)
+                    }
+                }
+            }
+        }
+    }
+
+    fn gps_coordinates(&self) -> u64 {
+        self.elements
+            .iter()
+            .filter_map(|(p, e)| match e {
+                Element::Box => Some(p.x as u64 + p.y as u64 * 100),
+                _ => None,
+            })
+            .sum()
+    }
+}
+
+utils::example_tests!(Day15);
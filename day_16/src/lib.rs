@@ -0,0 +1,167 @@
+use std::collections::HashSet;
+
+use tracing::Level;
+
+use utils::{
+    geom::{single_marker, Direction},
+    prelude::*,
+    search::dijkstra,
+};
+
+type Point = utils::geom::Point<i32>;
+type Maze = utils::geom::DenseGrid<i32, Element>;
+type State = (Point, Direction);
+
+utils::params! {
+    #[derive(Debug, Clone)]
+    pub struct Options {
+        /// The score of moving forward by one tile.
+        step: u64 = 1;
+        /// The score of turning by 90 degrees.
+        turn: u64 = 1000;
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = (Maze, Point, Point, Options);
+    type Params = Options;
+    type Answer1 = u64;
+    type Answer2 = usize;
+
+    fn parse(input: &str, options: &Options) -> Result<Self::Input> {
+        let (maze, markers) = Maze::parse_with_markers(input, &['S', 'E'], Element::try_from)?;
+        let start = single_marker(&markers, 'S')?;
+        let destination = single_marker(&markers, 'E')?;
+        Ok((maze, start, destination, options.clone()))
+    }
+
+    fn part_one((maze, start, destination, options): &Self::Input) -> Result<u64> {
+        part_one(maze, start, destination, options)
+    }
+
+    fn part_two((maze, start, destination, options): &Self::Input) -> Result<usize> {
+        part_two(maze, start, destination, options)
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example::new("example", include_str!("../input/example.txt"))
+                .part_one("7036")
+                .part_two("45"),
+            Example::new("example_2", include_str!("../input/example_2.txt"))
+                .part_one("11048")
+                .part_two("64"),
+        ]
+    }
+}
+
+#[tracing::instrument(level=Level::INFO,skip(maze,start,destination))]
+fn part_one(maze: &Maze, start: &Point, destination: &Point, options: &Options) -> Result<u64> {
+    dijkstra(
+        [(*start, Direction::Right)],
+        |state| forward(maze, options, state),
+        |(position, _)| position == destination,
+    )
+    .goal_cost()
+    .ok_or_else(|| Error::SolutionNotFound("no solution found".to_owned()))
+}
+
+/// Counts the tiles on any best path: those in a state whose score from the
+/// start plus its score to the destination equals the best score.
+#[tracing::instrument(level=Level::INFO,skip(maze,start,destination))]
+fn part_two(maze: &Maze, start: &Point, destination: &Point, options: &Options) -> Result<usize> {
+    let from_start = dijkstra(
+        [(*start, Direction::Right)],
+        |state| forward(maze, options, state),
+        |_| false,
+    )
+    .distances;
+    let to_destination = dijkstra(
+        Direction::iter().map(|direction| (*destination, direction)),
+        |state| backward(maze, options, state),
+        |_| false,
+    )
+    .distances;
+
+    let best = Direction::iter()
+        .filter_map(|direction| from_start.get(&(*destination, direction)))
+        .min()
+        .ok_or_else(|| Error::SolutionNotFound("no solution found".to_owned()))?;
+
+    let tiles: HashSet<Point> = from_start
+        .iter()
+        .filter(|(state, score)| {
+            to_destination
+                .get(state)
+                .is_some_and(|remaining| *score + remaining == *best)
+        })
+        .map(|((position, _), _)| *position)
+        .collect();
+
+    Ok(tiles.len())
+}
+
+fn is_open(maze: &Maze, position: &Point) -> bool {
+    matches!(maze.get(position), Some(Element::Empty))
+}
+
+/// The states reachable from a state in a single move: a step forward, or a
+/// turn towards an open tile.
+fn forward(maze: &Maze, options: &Options, &(position, orientation): &State) -> Vec<(State, u64)> {
+    let ahead = position.step(&orientation);
+    let right = orientation.rotate_right();
+    let left = orientation.rotate_left();
+
+    [
+        is_open(maze, &ahead).then_some(((ahead, orientation), options.step)),
+        is_open(maze, &position.step(&right)).then_some(((position, right), options.turn)),
+        is_open(maze, &position.step(&left)).then_some(((position, left), options.turn)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// The states from which a state can be reached in a single move, the
+/// reverse of [`forward`].
+fn backward(maze: &Maze, options: &Options, &(position, orientation): &State) -> Vec<(State, u64)> {
+    let behind = position.step(&orientation.opposite());
+    let turned_towards_open = is_open(maze, &position.step(&orientation));
+
+    [
+        is_open(maze, &behind).then_some(((behind, orientation), options.step)),
+        turned_towards_open.then_some(((position, orientation.rotate_left()), options.turn)),
+        turned_towards_open.then_some(((position, orientation.rotate_right()), options.turn)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+#[derive(Default)]
+pub enum Element {
+    Wall,
+    #[default]
+    Empty,
+}
+
+impl TryFrom<char> for Element {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self> {
+        match value {
+            '#' => Ok(Element::Wall),
+            '.' => Ok(Element::Empty),
+            _ => Err(parse_error(
+                "could not parse element",
+                &format!("{:?}", value),
+            )),
+        }
+    }
+}
+
+utils::example_tests!(Day16);
@@ -0,0 +1,102 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use tracing::Level;
+use utils::{
+    geom::BBox,
+    parse,
+    prelude::*,
+    scan,
+    search::{bfs, first_true},
+};
+
+type Point = utils::geom::Point<i32>;
+
+utils::params! {
+    #[derive(Debug, Clone)]
+    pub struct Options {
+        /// The number of bytes that have fallen before solving part one.
+        limit: usize = 1024, example 12;
+        /// The largest coordinate of the memory space.
+        dimension: usize = 70, example 6;
+    }
+}
+
+impl Options {
+    fn bounds(&self) -> (i32, i32) {
+        (self.dimension as i32, self.dimension as i32)
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = (Vec<Point>, Options);
+    type Params = Options;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str, options: &Options) -> Result<Self::Input> {
+        let points: Vec<Point> = parse::lines(input, |s| {
+            scan!(s, "{},{}" => i32, i32).map(|(x, y)| Point::new(x, y))
+        })?;
+
+        Ok((points, options.clone()))
+    }
+
+    fn part_one((points, options): &Self::Input) -> Result<usize> {
+        part_one(
+            &points.iter().take(options.limit).copied().collect_vec(),
+            options.bounds(),
+        )
+    }
+
+    fn part_two((points, options): &Self::Input) -> Result<String> {
+        part_two(points, options.limit, options.bounds()).map(|p| format!("{},{}", p.x, p.y))
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example::new("example", include_str!("../input/example.txt"))
+                .part_one("22")
+                .part_two("6,1"),
+        ]
+    }
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(obstacles, bounds))]
+fn part_one(obstacles: &[Point], bounds: (i32, i32)) -> Result<usize> {
+    shortest_path(obstacles, bounds)
+        .ok_or_else(|| Error::SolutionNotFound("no solution found".to_owned()))
+}
+
+/// The first obstacle after the first `limit` that cuts off the exit. Once cut
+/// off, the exit stays unreachable as more obstacles fall.
+#[tracing::instrument(level=Level::DEBUG,skip(obstacles,bounds))]
+fn part_two(obstacles: &[Point], limit: usize, bounds: (i32, i32)) -> Result<&Point> {
+    first_true(limit..obstacles.len() + 1, |i| {
+        shortest_path(&obstacles[..i], bounds).is_none()
+    })
+    .and_then(|i| obstacles.get(i.checked_sub(1)?))
+    .ok_or_else(|| Error::SolutionNotFound("no solution found".to_owned()))
+}
+
+/// The number of steps from the top left to the bottom right corner of the
+/// memory space, avoiding the obstacles.
+fn shortest_path(obstacles: &[Point], bounds: (i32, i32)) -> Option<usize> {
+    let obstacles: HashSet<Point> = obstacles.iter().copied().collect();
+    let space = BBox::new(0, bounds.0, 0, bounds.1);
+    let exit = Point::new(bounds.0, bounds.1);
+
+    let successors = |point: &Point| {
+        point
+            .neighbours()
+            .filter(|n| space.contains(n) && !obstacles.contains(n))
+            .collect_vec()
+    };
+    bfs([Point::new(0, 0)], successors, |p| *p == exit).goal_cost()
+}
+
+utils::example_tests!(Day18);
@@ -0,0 +1,152 @@
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+use tracing::Level;
+use utils::{memo::Memo, parse, prelude::*};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = (Lookup, Vec<Design>);
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str, _: &Self::Params) -> Result<Self::Input> {
+        let [patterns, designs] = parse::sections_array(input)?;
+
+        let lookup: Lookup = parse::list::<String>(patterns.text)?
+            .iter()
+            .map(|s| s.chars().collect_vec())
+            .collect();
+
+        let designs = designs
+            .text
+            .lines()
+            .map(|line| Design(line.chars().collect()))
+            .collect_vec();
+
+        Ok((lookup, designs))
+    }
+
+    fn part_one((lookup, designs): &Self::Input) -> Result<usize> {
+        part_one(lookup, designs)
+    }
+
+    fn part_two((lookup, designs): &Self::Input) -> Result<usize> {
+        part_two(lookup, designs)
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example::new("example", include_str!("../input/example.txt"))
+                .part_one("6")
+                .part_two("16"),
+        ]
+    }
+}
+
+pub struct Lookup {
+    patterns: HashMap<usize, HashSet<Vec<char>>>,
+    lens: Vec<usize>,
+}
+
+impl FromIterator<Vec<char>> for Lookup {
+    fn from_iter<T: IntoIterator<Item = Vec<char>>>(iter: T) -> Self {
+        let table: HashMap<usize, HashSet<Vec<char>>> = iter
+            .into_iter()
+            .map(|p| (p.len(), p))
+            .into_grouping_map()
+            .collect();
+        let max = table.keys().copied().max().unwrap_or(0);
+        let lens = table.keys().copied().sorted_by_key(|i| max - i).collect();
+
+        Self {
+            patterns: table,
+            lens,
+        }
+    }
+}
+
+impl Lookup {
+    fn next(&self, design: &[char], offset: usize) -> Vec<Vec<char>> {
+        self.lens
+            .iter()
+            .filter_map(|l| self.patterns.get(l).map(|s| (l, s)))
+            .filter_map(|(len, set)| {
+                let end = offset + len;
+                if end <= design.len() {
+                    let slice = &design[offset..end];
+                    if set.contains(slice) {
+                        Some(slice.to_vec())
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Design(Vec<char>);
+
+impl Design {
+    /// Whether the design can be made from the patterns. The memo is keyed by
+    /// the offset into the design, so it is reset before use.
+    #[tracing::instrument(level=Level::DEBUG,skip(self,patterns,memo))]
+    fn check(&self, patterns: &Lookup, memo: &mut Memo<usize, bool>) -> bool {
+        memo.reset();
+        memo.get(0, &|check, &index| {
+            self.0.len() == index
+                || patterns
+                    .next(&self.0, index)
+                    .iter()
+                    .any(|p| check(index + p.len()))
+        })
+    }
+
+    /// The number of ways the design can be made from the patterns, see
+    /// [`Design::check`].
+    fn count(&self, patterns: &Lookup, memo: &mut Memo<usize, usize>) -> usize {
+        memo.reset();
+        memo.get(0, &|count, &index| {
+            tracing::debug!(
+                resolved = self.0.iter().take(index).join(""),
+                "index: {}/{}",
+                index,
+                self.0.len()
+            );
+            if self.0.len() == index {
+                1
+            } else {
+                patterns
+                    .next(&self.0, index)
+                    .iter()
+                    .map(|p| count(index + p.len()))
+                    .sum()
+            }
+        })
+    }
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(patterns,designs))]
+fn part_one(patterns: &Lookup, designs: &[Design]) -> Result<usize> {
+    let mut memo = Memo::new("check");
+    Ok(designs
+        .iter()
+        .filter(|d| d.check(patterns, &mut memo))
+        .count())
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(patterns,designs))]
+fn part_two(patterns: &Lookup, designs: &[Design]) -> Result<usize> {
+    let mut memo = Memo::new("count");
+    Ok(designs.iter().map(|d| d.count(patterns, &mut memo)).sum())
+}
+
+utils::example_tests!(Day19);
@@ -0,0 +1,187 @@
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+use num::Signed;
+use tracing::Level;
+use utils::{
+    geom::{flood_fill, single_marker},
+    prelude::*,
+};
+
+type Point = utils::geom::Point<i16>;
+type Maze = utils::geom::DenseGrid<i16, Element>;
+
+utils::params! {
+    #[derive(Debug, Clone)]
+    pub struct Options {
+        /// The minimal number of picoseconds a cheat has to save.
+        threshold: usize = 100, example 50;
+        /// The longest cheat allowed in part two.
+        radius: i16 = 20;
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = (Racetrack, Options);
+    type Params = Options;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str, options: &Options) -> Result<Self::Input> {
+        let (maze, markers) = Maze::parse_with_markers(input, &['S', 'E'], Element::try_from)?;
+        let track = Racetrack {
+            maze,
+            start: single_marker(&markers, 'S')?,
+            end: single_marker(&markers, 'E')?,
+        };
+        Ok((track, options.clone()))
+    }
+
+    fn part_one((track, options): &Self::Input) -> Result<usize> {
+        part_one(track, options.threshold)
+    }
+
+    fn part_two((track, options): &Self::Input) -> Result<usize> {
+        part_two(track, options.threshold, options.radius)
+    }
+
+    fn examples() -> Vec<Example<Self>> {
+        vec![
+            Example::new("example", include_str!("../input/example.txt"))
+                .part_one("1")
+                .part_two("285"),
+            Example::new("all cheats", include_str!("../input/example.txt"))
+                .params(Options {
+                    threshold: 2,
+                    ..Options::example()
+                })
+                .part_one("44"),
+            Example::new("longest cheats", include_str!("../input/example.txt"))
+                .params(Options {
+                    threshold: 76,
+                    ..Options::example()
+                })
+                .part_two("3"),
+        ]
+    }
+}
+
+pub struct Racetrack {
+    maze: Maze,
+    start: Point,
+    end: Point,
+}
+
+#[derive(Default)]
+pub enum Element {
+    Wall,
+    #[default]
+    Empty,
+}
+
+impl TryFrom<char> for Element {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self> {
+        match value {
+            '#' => Ok(Element::Wall),
+            '.' => Ok(Element::Empty),
+            _ => Err(parse_error("invalid element", &value.to_string())),
+        }
+    }
+}
+
+fn fill(start: &Point, maze: &Maze) -> HashMap<Point, usize> {
+    flood_fill(*start, |p| matches!(maze.get(p), Some(Element::Empty)))
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(track))]
+fn part_one(track: &Racetrack, threshold: usize) -> Result<usize> {
+    let shortcuts = |p: &Point| -> HashSet<Point> {
+        let candidates = [
+            (p.x, p.y + 2),
+            (p.x + 1, p.y + 1),
+            (p.x + 2, p.y),
+            (p.x + 1, p.y - 1),
+            (p.x, p.y - 2),
+            (p.x - 1, p.y + 1),
+            (p.x - 2, p.y),
+            (p.x - 1, p.y - 1),
+        ];
+        candidates.iter().map(|(x, y)| Point::new(*x, *y)).collect()
+    };
+
+    solve(track, shortcuts, threshold)
+}
+
+fn solve<F>(track: &Racetrack, shortcut: F, threshold: usize) -> Result<usize>
+where
+    F: Fn(&Point) -> HashSet<Point>,
+{
+    let Racetrack { maze, start, end } = track;
+
+    let from_start = fill(start, maze);
+    let best_time = from_start
+        .get(end)
+        .ok_or_else(|| Error::SolutionNotFound("destination not reachable".to_owned()))?;
+    let to_destination = fill(end, maze);
+
+    let shortcuts: HashMap<usize, usize> = from_start
+        .iter()
+        .flat_map(|(from, from_start)| {
+            shortcut(from)
+                .iter()
+                .filter_map(|to| {
+                    let distance = (from.x - to.x).abs() + (from.y - to.y).abs();
+                    to_destination
+                        .get(to)
+                        .map(|from_end| from_start + from_end + distance as usize)
+                        .filter(|&time| time < *best_time)
+                        .map(|t| (best_time - t, 1))
+                })
+                .collect_vec()
+        })
+        .into_grouping_map()
+        .sum();
+
+    if tracing::enabled!(Level::DEBUG) {
+        shortcuts.iter().for_each(|(savings, count)| {
+            tracing::debug!(
+                "- There are {} cheats that save {} picoseconds",
+                count,
+                savings
+            );
+        });
+    }
+
+    Ok(shortcuts.iter().fold(0, |sum, (&savings, count)| {
+        if savings >= threshold {
+            sum + count
+        } else {
+            sum
+        }
+    }))
+}
+
+#[tracing::instrument(level=Level::DEBUG,skip(track))]
+fn part_two(track: &Racetrack, threshold: usize, radius: i16) -> Result<usize> {
+    let delta = (-radius..=radius)
+        .flat_map(|dx| (-radius..=radius).map(move |dy| (dx, dy)))
+        .filter(|(dx, dy)| dx.abs() + dy.abs() <= radius)
+        .collect_vec();
+
+    let shortcut = |point: &Point| -> HashSet<Point> {
+        delta
+            .iter()
+            .map(|(dx, dy)| Point::new(point.x + dx, point.y + dy))
+            .collect()
+    };
+
+    solve(track, shortcut, threshold)
+}
+
+utils::example_tests!(Day20);
@@ -0,0 +1,199 @@
+use std::{hint::black_box, str::FromStr, time::Duration};
+
+use console::style;
+use serde::Serialize;
+
+use crate::error::{parse_error, Error, Result};
+use crate::solution::{timed, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BenchFormat {
+    Table,
+    Csv,
+    Json,
+}
+
+impl FromStr for BenchFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "table" => Ok(BenchFormat::Table),
+            "csv" => Ok(BenchFormat::Csv),
+            "json" => Ok(BenchFormat::Json),
+            _ => Err(parse_error("expected one of table, csv or json", s)),
+        }
+    }
+}
+
+/// Summary statistics of the durations of repeated runs, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Stats {
+    pub runs: usize,
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub mean_ns: f64,
+    pub stddev_ns: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut nanos: Vec<u128> = samples.iter().map(Duration::as_nanos).collect();
+        nanos.sort_unstable();
+
+        let runs = nanos.len();
+        let median_ns = if runs.is_multiple_of(2) {
+            (nanos[runs / 2 - 1] + nanos[runs / 2]) / 2
+        } else {
+            nanos[runs / 2]
+        };
+        let mean_ns = nanos.iter().map(|&n| n as f64).sum::<f64>() / runs as f64;
+        let stddev_ns = if runs > 1 {
+            let variance = nanos
+                .iter()
+                .map(|&n| (n as f64 - mean_ns).powi(2))
+                .sum::<f64>()
+                / (runs - 1) as f64;
+            variance.sqrt()
+        } else {
+            0.0
+        };
+
+        Some(Stats {
+            runs,
+            min_ns: nanos[0],
+            median_ns,
+            mean_ns,
+            stddev_ns,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PhaseTiming {
+    pub day: u8,
+    pub phase: &'static str,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+/// Runs the parser and both parts of a solution `runs` times on the same
+/// input, collecting the timings of each phase separately.
+pub fn bench<S>(input: &str, params: &S::Params, runs: usize) -> Result<Vec<PhaseTiming>>
+where
+    S: Solution,
+{
+    let mut parse = Vec::with_capacity(runs);
+    let mut part_one = Vec::with_capacity(runs);
+    let mut part_two = Vec::with_capacity(runs);
+
+    for run in 0..runs {
+        tracing::debug!("benchmark run {}/{}", run + 1, runs);
+
+        let (model, elapsed) = timed(|| S::parse(black_box(input), params));
+        let model = model?;
+        parse.push(elapsed);
+
+        let (answer, elapsed) = timed(|| S::part_one(black_box(&model)));
+        black_box(answer?);
+        part_one.push(elapsed);
+
+        let (answer, elapsed) = timed(|| S::part_two(black_box(&model)));
+        black_box(answer?);
+        part_two.push(elapsed);
+    }
+
+    let timings = [("parse", parse), ("part 1", part_one), ("part 2", part_two)]
+        .into_iter()
+        .filter_map(|(phase, samples)| {
+            Stats::from_samples(&samples).map(|stats| PhaseTiming {
+                day: S::DAY,
+                phase,
+                stats,
+            })
+        })
+        .collect();
+
+    Ok(timings)
+}
+
+pub fn print_bench(timings: &[PhaseTiming], format: BenchFormat) -> Result<()> {
+    match format {
+        BenchFormat::Table => {
+            println!(
+                "{:<8} {:>6} {:>12} {:>12} {:>12} {:>12}",
+                style("phase").bold(),
+                style("runs").bold(),
+                style("min").bold(),
+                style("median").bold(),
+                style("mean").bold(),
+                style("stddev").bold()
+            );
+            for timing in timings {
+                let stats = &timing.stats;
+                println!(
+                    "{:<8} {:>6} {:>12} {:>12} {:>12} {:>12}",
+                    style(timing.phase).bright(),
+                    stats.runs,
+                    format!("{:.2?}", Duration::from_nanos(stats.min_ns as u64)),
+                    style(format!(
+                        "{:.2?}",
+                        Duration::from_nanos(stats.median_ns as u64)
+                    ))
+                    .green(),
+                    format!("{:.2?}", Duration::from_secs_f64(stats.mean_ns / 1e9)),
+                    format!("±{:.2?}", Duration::from_secs_f64(stats.stddev_ns / 1e9))
+                );
+            }
+        }
+        BenchFormat::Csv => {
+            println!("day,phase,runs,min_ns,median_ns,mean_ns,stddev_ns");
+            for timing in timings {
+                let stats = &timing.stats;
+                println!(
+                    "{},{},{},{},{},{:.0},{:.0}",
+                    timing.day,
+                    timing.phase,
+                    stats.runs,
+                    stats.min_ns,
+                    stats.median_ns,
+                    stats.mean_ns,
+                    stats.stddev_ns
+                );
+            }
+        }
+        BenchFormat::Json => {
+            println!("{}", serde_json::to_string(timings)?);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_nanos);
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min_ns, 1);
+        assert_eq!(stats.median_ns, 2);
+        assert_eq!(stats.mean_ns, 2.5);
+        assert!((stats.stddev_ns - 1.2909944).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_stats_single_sample() {
+        let stats = Stats::from_samples(&[Duration::from_nanos(7)]).unwrap();
+        assert_eq!(stats.median_ns, 7);
+        assert_eq!(stats.stddev_ns, 0.0);
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+}
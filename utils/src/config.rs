@@ -1,42 +1,358 @@
-use crate::error::Result;
+use std::path::PathBuf;
 
-pub trait ExampleSettings {
-    fn example<F>(f: F) -> Self
-    where
-        F: FnOnce() -> Self;
+use crate::bench::BenchFormat;
+use crate::error::{parse_error, Error, Result};
+use crate::io::output::OutputFormat;
+use crate::puzzle::Part;
 
-    fn try_example<F>(f: F) -> Result<Self>
-    where
-        Self: Sized,
-        F: FnOnce() -> Result<Self>;
+const USAGE: &str = "\
+USAGE:
+  day_NN [OPTIONS] [INPUT]
+
+ARGS:
+  [INPUT]  input file, or '-' for stdin (default: the first existing one of
+           $AOC_INPUT_DIR/dayNN.txt, inputs/dayNN.txt, day_NN/input/puzzle.txt
+           or input/puzzle.txt, or else stdin when it is piped)
+
+OPTIONS:
+  --example                use the example input and parameters
+  --part <PART>            only run part 1 or part 2
+  --format <FORMAT>        json, plain or pretty (default: pretty)
+  --param <KEY=VALUE>      override a puzzle parameter, can be repeated
+  --bench <RUNS>           time parsing and both parts over a number of runs
+  --bench-format <FORMAT>  table, csv or json (default: table)
+  --check                  compare the answers with the ones in the answers file
+  --record                 like --check, but also store answers that are not
+                           in the answers file yet
+  --answers <FILE>         answers file used by --check (default: answers.toml)
+  -h, --help               print this message
+";
+
+/// The command line arguments of a single day, parsed once in `main`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Args {
+    pub input: Option<PathBuf>,
+    pub example: bool,
+    pub part: Option<Part>,
+    pub format: OutputFormat,
+    pub bench: Option<usize>,
+    pub bench_format: BenchFormat,
+    pub check: bool,
+    /// Whether `--check` stores answers that are not known yet.
+    pub record: bool,
+    pub answers: PathBuf,
+    pub params: Vec<(String, String)>,
 }
 
-impl<D> ExampleSettings for D
-where
-    D: Default,
-{
-    fn example<F>(f: F) -> Self
-    where
-        F: FnOnce() -> Self,
-    {
-        let mut pargs = pico_args::Arguments::from_env();
-        if pargs.contains("--example") {
-            f()
-        } else {
-            Default::default()
+impl Default for Args {
+    fn default() -> Self {
+        Args {
+            input: None,
+            example: false,
+            part: None,
+            format: OutputFormat::default(),
+            bench: None,
+            bench_format: BenchFormat::Table,
+            check: false,
+            record: false,
+            answers: PathBuf::from("answers.toml"),
+            params: Vec::new(),
         }
     }
+}
 
-    fn try_example<F>(f: F) -> Result<Self>
-    where
-        Self: Sized,
-        F: FnOnce() -> Result<Self>,
-    {
+impl Args {
+    /// Parses the arguments of the current process, printing the usage and
+    /// exiting when `--help` is given.
+    pub fn from_env() -> Result<Self> {
         let mut pargs = pico_args::Arguments::from_env();
-        if pargs.contains("--example") {
-            f()
+        if pargs.contains(["-h", "--help"]) {
+            print!("{}", USAGE);
+            std::process::exit(0);
+        }
+        Args::parse(pargs)
+    }
+
+    pub fn parse(pargs: pico_args::Arguments) -> Result<Self> {
+        let (mut args, free) = Args::parse_options(pargs)?;
+        for arg in free {
+            if args.input.is_some() {
+                return Err(parse_error("only a single input can be given", &arg));
+            }
+            args.input = Some(PathBuf::from(arg));
+        }
+        Ok(args)
+    }
+
+    /// Parses the options, leaving the free arguments to the caller. The input
+    /// is not set.
+    pub fn parse_options(mut pargs: pico_args::Arguments) -> Result<(Self, Vec<String>)> {
+        let record = pargs.contains("--record");
+        let args = Args {
+            example: pargs.contains("--example"),
+            part: pargs.opt_value_from_fn("--part", |s| {
+                s.parse::<u8>().map_err(Error::from)?.try_into()
+            })?,
+            format: pargs.opt_value_from_str("--format")?.unwrap_or_default(),
+            bench: pargs.opt_value_from_str("--bench")?,
+            bench_format: pargs
+                .opt_value_from_str("--bench-format")?
+                .unwrap_or(BenchFormat::Table),
+            check: record || pargs.contains("--check"),
+            record,
+            answers: pargs
+                .opt_value_from_str("--answers")?
+                .unwrap_or_else(|| PathBuf::from("answers.toml")),
+            params: pargs.values_from_fn("--param", |s| {
+                s.split_once('=')
+                    .map(|(key, value)| (key.trim().to_owned(), value.trim().to_owned()))
+                    .ok_or_else(|| parse_error("expected KEY=VALUE", s))
+            })?,
+            input: None,
+        };
+
+        let mut free = Vec::new();
+        for arg in pargs.finish() {
+            let arg = arg.to_string_lossy();
+            if arg.starts_with('-') && arg != "-" {
+                return Err(parse_error("unknown option, see --help", &arg));
+            }
+            free.push(arg.into_owned());
+        }
+
+        Ok((args, free))
+    }
+
+    /// Whether `part` should be solved in this run.
+    pub fn runs(&self, part: Part) -> bool {
+        self.part.is_none_or(|selected| selected == part)
+    }
+}
+
+/// Puzzle parameters that are not part of the input itself, such as the size
+/// of a room. Each parameter has a default for the real puzzle and a value used
+/// with `--example`, and can be overridden with `--param key=value`.
+///
+/// Implementations are usually generated with [`params!`](crate::params).
+pub trait Params: Default {
+    /// The parameters used when running with `--example`.
+    fn example() -> Self {
+        Self::default()
+    }
+
+    /// The names of the parameters that can be overridden.
+    fn names() -> &'static [&'static str] {
+        &[]
+    }
+
+    fn set(&mut self, key: &str, _value: &str) -> Result<()> {
+        Err(unknown_param(key, Self::names()))
+    }
+
+    /// The example or default parameters, with the `--param` overrides of
+    /// `args` applied.
+    fn from_args(args: &Args) -> Result<Self> {
+        let mut params = if args.example {
+            Self::example()
         } else {
-            Ok(Default::default())
+            Self::default()
+        };
+        for (key, value) in &args.params {
+            params.set(key, value)?;
+        }
+        Ok(params)
+    }
+}
+
+impl Params for () {}
+
+pub fn unknown_param(key: &str, names: &[&str]) -> Error {
+    if names.is_empty() {
+        parse_error("unknown parameter, this day has no parameters", key)
+    } else {
+        parse_error(
+            &format!("unknown parameter, expected one of {}", names.join(", ")),
+            key,
+        )
+    }
+}
+
+/// Declares a struct of puzzle parameters with their defaults and, optionally,
+/// the values used with `--example`, and implements [`Params`] for it.
+///
+/// ```
+/// utils::params! {
+///     #[derive(Debug, Clone)]
+///     pub struct Options {
+///         /// The number of bytes that have fallen.
+///         pub limit: usize = 1024, example 12;
+///         pub dimension: usize = 70, example 6;
+///     }
+/// }
+///
+/// use utils::config::Params;
+/// assert_eq!(Options::example().limit, 12);
+/// ```
+#[macro_export]
+macro_rules! params {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident : $ty:ty = $default:expr $(, example $example:expr)?
+            );* $(;)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $(
+                $(#[$field_meta])*
+                $field_vis $field: $ty,
+            )*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name {
+                    $($field: $default,)*
+                }
+            }
+        }
+
+        impl $crate::config::Params for $name {
+            fn example() -> Self {
+                $name {
+                    $($field: $crate::params!(@example $default $(, $example)?),)*
+                }
+            }
+
+            fn names() -> &'static [&'static str] {
+                &[$(stringify!($field)),*]
+            }
+
+            fn set(&mut self, key: &str, value: &str) -> $crate::error::Result<()> {
+                match key {
+                    $(
+                        stringify!($field) => {
+                            self.$field = value.parse().map_err(|_| {
+                                $crate::error::parse_error(
+                                    concat!("invalid value for parameter ", stringify!($field)),
+                                    value,
+                                )
+                            })?;
+                        }
+                    )*
+                    _ => return Err($crate::config::unknown_param(key, Self::names())),
+                }
+                Ok(())
+            }
+        }
+    };
+    (@example $default:expr) => {
+        $default
+    };
+    (@example $default:expr, $example:expr) => {
+        $example
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsString;
+
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args> {
+        Args::parse(pico_args::Arguments::from_vec(
+            args.iter().map(OsString::from).collect(),
+        ))
+    }
+
+    #[test]
+    fn test_parse() {
+        let args = parse(&[
+            "--example",
+            "--part",
+            "2",
+            "--format",
+            "json",
+            "--param",
+            "limit=12",
+            "--param",
+            "dimension = 6",
+            "input.txt",
+        ])
+        .unwrap();
+
+        assert!(args.example);
+        assert_eq!(args.part, Some(Part::Two));
+        assert_eq!(args.format, OutputFormat::Json);
+        assert_eq!(
+            args.params,
+            vec![
+                ("limit".to_owned(), "12".to_owned()),
+                ("dimension".to_owned(), "6".to_owned())
+            ]
+        );
+        assert_eq!(args.input, Some(PathBuf::from("input.txt")));
+        assert!(!args.runs(Part::One));
+        assert!(!args.check);
+
+        let args = parse(&["--record"]).unwrap();
+        assert!(args.check && args.record);
+
+        assert_eq!(parse(&[]).unwrap(), Args::default());
+    }
+
+    crate::params! {
+        #[derive(Debug, PartialEq)]
+        struct Options {
+            limit: usize = 1024, example 12;
+            dimension: i32 = 70;
         }
     }
+
+    #[test]
+    fn test_params() {
+        assert_eq!(
+            Options::example(),
+            Options {
+                limit: 12,
+                dimension: 70
+            }
+        );
+
+        let args = parse(&["--example", "--param", "dimension=6"]).unwrap();
+        assert_eq!(
+            Options::from_args(&args).unwrap(),
+            Options {
+                limit: 12,
+                dimension: 6
+            }
+        );
+
+        let args = parse(&["--param", "limit=10"]).unwrap();
+        assert_eq!(
+            Options::from_args(&args).unwrap(),
+            Options {
+                limit: 10,
+                dimension: 70
+            }
+        );
+
+        let args = parse(&["--param", "limit=-1"]).unwrap();
+        assert!(Options::from_args(&args).is_err());
+        let args = parse(&["--param", "size=10"]).unwrap();
+        assert!(Options::from_args(&args).is_err());
+        assert!(<()>::from_args(&args).is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse(&["--exmaple"]).is_err());
+        assert!(parse(&["--part", "3"]).is_err());
+        assert!(parse(&["--param", "limit"]).is_err());
+        assert!(parse(&["a.txt", "b.txt"]).is_err());
+    }
 }
@@ -0,0 +1,159 @@
+/// A cycle in the sequence of states `x0, f(x0), f(f(x0)), ...` of a
+/// deterministic simulation with finitely many states.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<S> {
+    /// The number of steps before the sequence enters the cycle.
+    pub start: usize,
+    /// The number of steps after which the states repeat.
+    pub length: usize,
+    /// The first state of the cycle, reached after `start` steps.
+    pub state: S,
+}
+
+impl<S> Cycle<S> {
+    /// The earliest step at which the simulation is in the same state as at
+    /// `step`.
+    pub fn reduce(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.length
+        }
+    }
+}
+
+/// Floyd's tortoise and hare: finds the cycle with two pointers moving at
+/// different speeds, keeping only two states in memory.
+pub fn floyd<S, F>(initial: S, mut step: F) -> Cycle<S>
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> S,
+{
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let half = step(&hare);
+        hare = step(&half);
+    }
+
+    let mut start = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    Cycle {
+        start,
+        length,
+        state: tortoise,
+    }
+}
+
+/// Brent's algorithm: finds the cycle by teleporting the tortoise to the hare
+/// at every power of two, which usually takes fewer steps than [`floyd`].
+pub fn brent<S, F>(initial: S, mut step: F) -> Cycle<S>
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> S,
+{
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    let mut start = 0;
+    tortoise = initial.clone();
+    hare = (0..length).fold(initial, |state, _| step(&state));
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle {
+        start,
+        length,
+        state: tortoise,
+    }
+}
+
+/// The state after `n` steps, simulating no more than the steps needed to
+/// find the cycle and one more trip around it.
+pub fn state_at<S, F>(initial: S, mut step: F, n: usize) -> S
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> S,
+{
+    let cycle = brent(initial.clone(), &mut step);
+    if n < cycle.start {
+        (0..n).fold(initial, |state, _| step(&state))
+    } else {
+        let remaining = (n - cycle.start) % cycle.length;
+        (0..remaining).fold(cycle.state, |state, _| step(&state))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 2 -> ...
+    fn next(state: &usize) -> usize {
+        [1, 2, 3, 4, 5, 2][*state]
+    }
+
+    #[test]
+    fn test_detection() {
+        let expected = Cycle {
+            start: 2,
+            length: 4,
+            state: 2,
+        };
+        assert_eq!(floyd(0, next), expected);
+        assert_eq!(brent(0, next), expected);
+
+        let fixed_point = Cycle {
+            start: 0,
+            length: 1,
+            state: 7,
+        };
+        assert_eq!(floyd(7, |&s| s), fixed_point);
+        assert_eq!(brent(7, |&s| s), fixed_point);
+
+        let lcg = |s: &u64| (s * 37 + 11) % 1000;
+        assert_eq!(floyd(3, lcg), brent(3, lcg));
+    }
+
+    #[test]
+    fn test_state_at() {
+        let cycle = brent(0, next);
+        assert_eq!(cycle.reduce(1), 1);
+        assert_eq!(cycle.reduce(6), 2);
+        assert_eq!(cycle.reduce(1_000_000_001), 5);
+
+        for n in 0..20 {
+            let simulated = (0..n).fold(0, |state, _| next(&state));
+            assert_eq!(state_at(0, next, n), simulated);
+        }
+        assert_eq!(state_at(0, next, 1_000_000_001), 5);
+    }
+}
@@ -1,36 +1,265 @@
-use std::num::TryFromIntError;
+use std::{convert::Infallible, num::TryFromIntError, path::PathBuf};
 
 use thiserror::Error;
 
-#[derive(Debug, Error)]
+#[derive(Error)]
 pub enum Error {
     #[error(transparent)]
     IO(#[from] std::io::Error),
 
-    #[error("Error parsing input: {0}")]
-    Parse(String, #[source] Option<Box<dyn std::error::Error>>),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error("Error parsing input: {message}")]
+    Parse {
+        message: String,
+        /// The text that could not be parsed, used to find the column when
+        /// the error is placed in a line with [`Error::in_line`].
+        text: Option<String>,
+        #[source]
+        source: Option<Box<dyn std::error::Error>>,
+    },
+
+    #[error("{}", render_position(.message, *.line, *.column, .text))]
+    ParseAt {
+        message: String,
+        /// One-based line number.
+        line: usize,
+        /// One-based column, counted in characters.
+        column: usize,
+        /// The full line of the input.
+        text: String,
+        #[source]
+        source: Option<Box<dyn std::error::Error>>,
+    },
+
+    #[error(transparent)]
+    TomlDe(#[from] toml::de::Error),
+
+    #[error(transparent)]
+    TomlSer(#[from] toml::ser::Error),
+
+    #[error("No input found, tried: {}", .0.iter().map(|p| p.display().to_string()).collect::<Vec<_>>().join(", "))]
+    InputNotFound(Vec<PathBuf>),
 
     #[error("Solution not found: {0}")]
     SolutionNotFound(String),
+
+    #[error("{0} answer(s) did not match the expected answer")]
+    CheckFailed(usize),
 }
 
 pub fn parse_error(msg: &str, line: &str) -> Error {
-    Error::Parse(format!("error parsing '{}': {}", line, msg), None)
+    Error::Parse {
+        message: format!("error parsing '{}': {}", line, msg),
+        text: Some(line.to_owned()),
+        source: None,
+    }
+}
+
+/// An error for `text` that could not be parsed into a value, keeping the
+/// error of the parser as its source.
+pub fn value_error<E>(text: &str, error: E) -> Error
+where
+    Error: From<E>,
+{
+    let source = match Error::from(error) {
+        Error::Parse {
+            source: Some(source),
+            ..
+        } => source,
+        other => Box::new(other),
+    };
+    Error::Parse {
+        message: format!("could not parse '{}'", text),
+        text: Some(text.to_owned()),
+        source: Some(source),
+    }
+}
+
+/// A parse error pointing at a single character of the input. The `line` and
+/// `column` are zero-based, as produced by `enumerate`, and `text` is the full
+/// line.
+pub fn parse_error_at(msg: &str, line: usize, column: usize, text: &str) -> Error {
+    Error::ParseAt {
+        message: msg.to_owned(),
+        line: line + 1,
+        column: column + 1,
+        text: text.to_owned(),
+        source: None,
+    }
+}
+
+/// Renders errors like their `Display`, followed by their sources, so that the
+/// error returned from `main` is readable.
+impl std::fmt::Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)?;
+        let mut source = std::error::Error::source(self);
+        while let Some(cause) = source {
+            write!(f, "\n  caused by: {}", cause)?;
+            source = cause.source();
+        }
+        Ok(())
+    }
+}
+
+impl Error {
+    /// Attaches a position in the input to an error, see [`parse_error_at`].
+    /// Errors that already carry a position are kept as they are, any other
+    /// error becomes the source of the positional one.
+    pub fn at(self, line: usize, column: usize, text: &str) -> Error {
+        let (message, source) = match self {
+            Error::ParseAt { .. } => return self,
+            Error::Parse {
+                message, source, ..
+            } => (message, source),
+            other => ("invalid value".to_owned(), Some(Box::new(other) as Box<_>)),
+        };
+        Error::ParseAt {
+            message,
+            line: line + 1,
+            column: column + 1,
+            text: text.to_owned(),
+            source,
+        }
+    }
+
+    /// Attaches a zero-based line of the input to an error, pointing at the
+    /// text that could not be parsed if it is found in the line, or else at
+    /// the start of the line. See [`Error::at`].
+    pub fn in_line(self, line: usize, text: &str) -> Error {
+        let column = match &self {
+            Error::Parse {
+                text: Some(snippet),
+                ..
+            } => text
+                .find(snippet.as_str())
+                .map_or(0, |i| text[..i].chars().count()),
+            _ => 0,
+        };
+        self.at(line, column, text)
+    }
+}
+
+fn render_position(message: &str, line: usize, column: usize, text: &str) -> String {
+    let gutter = " ".repeat(line.to_string().len());
+    format!(
+        "Error parsing input: {message}\n\
+         {gutter}--> line {line}, column {column}\n\
+         {gutter} |\n\
+         {line} | {text}\n\
+         {gutter} | {caret:>column$}",
+        caret = "^",
+    )
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
 
 impl From<std::num::ParseIntError> for Error {
     fn from(value: std::num::ParseIntError) -> Self {
-        Error::Parse("error parsing integer".to_string(), Some(Box::new(value)))
+        Error::Parse {
+            message: "error parsing integer".to_string(),
+            text: None,
+            source: Some(Box::new(value)),
+        }
     }
 }
 
 impl From<TryFromIntError> for Error {
     fn from(value: TryFromIntError) -> Self {
-        Error::Parse(
-            "error converting integer".to_string(),
-            Some(Box::new(value)),
-        )
+        Error::Parse {
+            message: "error converting integer".to_string(),
+            text: None,
+            source: Some(Box::new(value)),
+        }
+    }
+}
+
+impl From<pico_args::Error> for Error {
+    fn from(value: pico_args::Error) -> Self {
+        Error::Parse {
+            message: "error parsing arguments".to_string(),
+            text: None,
+            source: Some(Box::new(value)),
+        }
+    }
+}
+
+impl From<Infallible> for Error {
+    fn from(value: Infallible) -> Self {
+        match value {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_position() {
+        let error = parse_error_at("could not parse map", 9, 3, "#..x.#");
+        assert_eq!(
+            error.to_string(),
+            "Error parsing input: could not parse map\n  \
+             --> line 10, column 4\n   \
+             |\n\
+             10 | #..x.#\n   \
+             |    ^"
+        );
+    }
+
+    #[test]
+    fn test_at() {
+        let error = parse_error("invalid element", "x").at(0, 2, "..x");
+        assert!(matches!(
+            error,
+            Error::ParseAt {
+                line: 1,
+                column: 3,
+                ..
+            }
+        ));
+
+        let error = Error::from("x".parse::<u8>().unwrap_err()).at(0, 0, "x");
+        assert!(
+            std::error::Error::source(&error).is_some_and(|e| e.is::<std::num::ParseIntError>())
+        );
+
+        let error = Error::CheckFailed(1).at(0, 0, "x");
+        assert!(std::error::Error::source(&error).is_some_and(|e| e.is::<Error>()));
+
+        let error = parse_error("invalid element", "x").at(0, 2, "..x");
+        let error = error.at(5, 5, "ignored");
+        assert!(matches!(
+            error,
+            Error::ParseAt {
+                line: 1,
+                column: 3,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_in_line() {
+        let error = parse_error("expected a digit", "x").in_line(3, "12x4");
+        assert!(matches!(
+            error,
+            Error::ParseAt {
+                line: 4,
+                column: 3,
+                ..
+            }
+        ));
+
+        let error = value_error("-1", "-1".parse::<u8>().unwrap_err()).in_line(0, "7,-1");
+        assert!(matches!(error, Error::ParseAt { column: 3, .. }));
+        assert!(
+            std::error::Error::source(&error).is_some_and(|e| e.is::<std::num::ParseIntError>())
+        );
+
+        let error = Error::CheckFailed(1).in_line(0, "x");
+        assert!(matches!(error, Error::ParseAt { column: 1, .. }));
     }
 }
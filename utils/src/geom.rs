@@ -1,9 +1,10 @@
 use itertools::Itertools;
 use std::{
-    collections::HashMap,
+    collections::{hash_map, HashMap, HashSet},
     fmt::Debug,
     hash::Hash,
     iter,
+    marker::PhantomData,
     ops::{Add, Sub},
     str::FromStr,
 };
@@ -75,6 +76,31 @@ impl<T> Point<T> {
     {
         Direction::iter().map(|direction| self.step(&direction))
     }
+
+    #[inline]
+    pub fn step8(&self, direction: &Direction8) -> Self
+    where
+        T: Copy + num::traits::Zero + num::traits::One + num::traits::Signed,
+    {
+        self.move_by(direction.d().into())
+    }
+
+    /// The eight points around this one, clockwise starting with the one above.
+    pub fn neighbours8(&self) -> impl Iterator<Item = Self> + '_
+    where
+        T: Copy + num::traits::Zero + num::traits::One + num::traits::Signed,
+    {
+        Direction8::iter().map(|direction| self.step8(&direction))
+    }
+}
+
+/// The `len` points on a straight line from `start` towards `direction`,
+/// starting with `start` itself.
+pub fn ray<T>(start: Point<T>, direction: Direction8, len: usize) -> impl Iterator<Item = Point<T>>
+where
+    T: Copy + num::traits::Zero + num::traits::One + num::traits::Signed,
+{
+    iter::successors(Some(start), move |point| Some(point.step8(&direction))).take(len)
 }
 
 impl<T> Sub for Point<T>
@@ -279,7 +305,124 @@ where
     }
 }
 
-#[derive(Debug, Clone)]
+/// The eight directions of a compass, for scans that include the diagonals.
+#[derive(Debug, Clone, PartialEq, Copy, Hash, Eq)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    const ALL: [Direction8; 8] = [
+        Direction8::N,
+        Direction8::NE,
+        Direction8::E,
+        Direction8::SE,
+        Direction8::S,
+        Direction8::SW,
+        Direction8::W,
+        Direction8::NW,
+    ];
+
+    /// All eight directions, clockwise starting with north.
+    pub fn iter() -> impl Iterator<Item = Direction8> {
+        Self::ALL.into_iter()
+    }
+
+    pub fn diagonals() -> impl Iterator<Item = Direction8> {
+        Self::iter().filter(Direction8::is_diagonal)
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        matches!(
+            self,
+            Direction8::NE | Direction8::SE | Direction8::SW | Direction8::NW
+        )
+    }
+
+    /// The diagonal between two perpendicular directions, e.g. north-east for
+    /// up and right.
+    pub fn between(a: Direction, b: Direction) -> Option<Self> {
+        match (a, b) {
+            (Direction::Up, Direction::Right) | (Direction::Right, Direction::Up) => {
+                Some(Direction8::NE)
+            }
+            (Direction::Down, Direction::Right) | (Direction::Right, Direction::Down) => {
+                Some(Direction8::SE)
+            }
+            (Direction::Down, Direction::Left) | (Direction::Left, Direction::Down) => {
+                Some(Direction8::SW)
+            }
+            (Direction::Up, Direction::Left) | (Direction::Left, Direction::Up) => {
+                Some(Direction8::NW)
+            }
+            _ => None,
+        }
+    }
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn d<T>(&self) -> (T, T)
+    where
+        T: num::traits::Zero + num::traits::One + num::traits::Signed,
+    {
+        match self {
+            Direction8::N => (T::zero(), -T::one()),
+            Direction8::NE => (T::one(), -T::one()),
+            Direction8::E => (T::one(), T::zero()),
+            Direction8::SE => (T::one(), T::one()),
+            Direction8::S => (T::zero(), T::one()),
+            Direction8::SW => (-T::one(), T::one()),
+            Direction8::W => (-T::one(), T::zero()),
+            Direction8::NW => (-T::one(), -T::one()),
+        }
+    }
+
+    pub fn opposite(&self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    /// Turns 45 degrees counter-clockwise.
+    pub fn rotate_left(&self) -> Self {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    /// Turns 45 degrees clockwise.
+    pub fn rotate_right(&self) -> Self {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::N,
+            Direction::Right => Direction8::E,
+            Direction::Down => Direction8::S,
+            Direction::Left => Direction8::W,
+        }
+    }
+}
+
+impl<T> From<Direction8> for Vector<T>
+where
+    T: num::traits::Zero + num::traits::One + num::traits::Signed,
+{
+    fn from(value: Direction8) -> Self {
+        let d: (T, T) = value.d();
+        Vector { dx: d.0, dy: d.1 }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BBox<T> {
     pub min_x: T,
     pub max_x: T,
@@ -368,7 +511,7 @@ where
             + 'static,
     {
         let max = self.max_y;
-        iter::successors(Some(T::zero()), |i| Some(i.add(T::one()))).take_while(move |&y| y <= max)
+        iter::successors(Some(self.min_y), |i| Some(i.add(T::one()))).take_while(move |&y| y <= max)
     }
 
     pub fn x_iter(&self) -> impl Iterator<Item = T> + '_
@@ -381,7 +524,7 @@ where
             + 'static,
     {
         let max = self.max_x;
-        iter::successors(Some(T::zero()), |i| Some(i.add(T::one()))).take_while(move |&x| x <= max)
+        iter::successors(Some(self.min_x), |i| Some(i.add(T::one()))).take_while(move |&x| x <= max)
     }
 
     pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = Point<T>> + '_>
@@ -418,55 +561,188 @@ where
     }
 }
 
+impl<T> BBox<T>
+where
+    T: std::cmp::PartialOrd + Copy + num::traits::Num,
+{
+    pub fn width(&self) -> T {
+        self.max_x - self.min_x + T::one()
+    }
+
+    pub fn height(&self) -> T {
+        self.max_y - self.min_y + T::one()
+    }
+
+    pub fn area(&self) -> T {
+        self.width() * self.height()
+    }
+
+    /// The box covered by both boxes, or `None` when they do not overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let bbox = BBox {
+            min_x: max_of(self.min_x, other.min_x),
+            max_x: min_of(self.max_x, other.max_x),
+            min_y: max_of(self.min_y, other.min_y),
+            max_y: min_of(self.max_y, other.max_y),
+        };
+        (bbox.min_x <= bbox.max_x && bbox.min_y <= bbox.max_y).then_some(bbox)
+    }
+
+    /// The smallest box containing both boxes.
+    pub fn union(&self, other: &Self) -> Self {
+        BBox {
+            min_x: min_of(self.min_x, other.min_x),
+            max_x: max_of(self.max_x, other.max_x),
+            min_y: min_of(self.min_y, other.min_y),
+            max_y: max_of(self.max_y, other.max_y),
+        }
+    }
+
+    /// Grows the box by `margin` on every side.
+    pub fn expand(&self, margin: T) -> Self {
+        BBox {
+            min_x: self.min_x - margin,
+            max_x: self.max_x + margin,
+            min_y: self.min_y - margin,
+            max_y: self.max_y + margin,
+        }
+    }
+
+    /// Splits the box into its top left, top right, bottom left and bottom
+    /// right quarters. With an odd width or height the middle column or row is
+    /// not part of any quadrant. Boxes less than two cells wide or high have
+    /// no quadrants.
+    pub fn quadrants(&self) -> Option<[Self; 4]> {
+        if self.max_x <= self.min_x || self.max_y <= self.min_y {
+            return None;
+        }
+
+        let two = T::one() + T::one();
+        let half_x = self.width() / two - T::one();
+        let half_y = self.height() / two - T::one();
+        let (left, right) = (
+            (self.min_x, self.min_x + half_x),
+            (self.max_x - half_x, self.max_x),
+        );
+        let (top, bottom) = (
+            (self.min_y, self.min_y + half_y),
+            (self.max_y - half_y, self.max_y),
+        );
+
+        Some(
+            [(left, top), (right, top), (left, bottom), (right, bottom)]
+                .map(|((min_x, max_x), (min_y, max_y))| BBox::new(min_x, max_x, min_y, max_y)),
+        )
+    }
+
+    /// The corners of the box, clockwise starting at the top left.
+    pub fn corners(&self) -> [Point<T>; 4] {
+        [
+            Point::new(self.min_x, self.min_y),
+            Point::new(self.max_x, self.min_y),
+            Point::new(self.max_x, self.max_y),
+            Point::new(self.min_x, self.max_y),
+        ]
+    }
+}
+
+fn min_of<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+fn max_of<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+/// The positions of the marker characters found while parsing a grid.
+pub type Markers<T> = HashMap<char, Vec<Point<T>>>;
+
+/// The position of a marker that should occur exactly once in a grid.
+pub fn single_marker<T>(markers: &Markers<T>, marker: char) -> Result<Point<T>, Error>
+where
+    T: Copy,
+{
+    match markers.get(&marker).map(Vec::as_slice) {
+        Some([point]) => Ok(*point),
+        Some(points) => Err(crate::error::parse_error(
+            &format!("expected a single marker, found {}", points.len()),
+            &marker.to_string(),
+        )),
+        None => Err(crate::error::parse_error(
+            "marker not found",
+            &marker.to_string(),
+        )),
+    }
+}
+
+/// A sparse grid of cells keyed by their position. The bounds always span
+/// exactly the cells that are present, on both axes and including negative
+/// coordinates.
 #[derive(Debug, Clone)]
 pub struct Grid<T, E> {
     locations: HashMap<Point<T>, E>,
-    pub max_x: T,
-    pub max_y: T,
+    bounds: Option<BBox<T>>,
 }
 
-impl<T, E> Default for Grid<T, E>
+impl<T, E> PartialEq for Grid<T, E>
 where
-    T: num::traits::Zero,
+    T: Hash + Eq,
+    E: PartialEq,
 {
+    fn eq(&self, other: &Self) -> bool {
+        self.locations == other.locations
+    }
+}
+
+impl<T, E> Eq for Grid<T, E>
+where
+    T: Hash + Eq,
+    E: Eq,
+{
+}
+
+impl<T, E> Default for Grid<T, E> {
     fn default() -> Self {
         Grid {
             locations: HashMap::new(),
-            max_x: T::zero(),
-            max_y: T::zero(),
+            bounds: None,
         }
     }
 }
 
 impl<T, E> Grid<T, E>
 where
-    T: Hash + Eq + Copy,
+    T: Hash + Eq + Copy + PartialOrd,
 {
-    pub fn emtpy() -> Self
-    where
-        T: num::traits::Zero,
-    {
-        Grid {
-            locations: HashMap::new(),
-            max_x: T::zero(),
-            max_y: T::zero(),
-        }
+    pub fn emtpy() -> Self {
+        Grid::default()
     }
 
-    pub fn contains(&self, point: &Point<T>) -> bool
-    where
-        T: std::cmp::PartialOrd + num::traits::Zero,
-    {
-        point.x >= T::zero()
-            && point.x <= self.max_x
-            && point.y >= T::zero()
-            && point.y <= self.max_y
+    pub fn len(&self) -> usize {
+        self.locations.len()
     }
 
-    pub fn filter(&self, point: Point<T>) -> Option<Point<T>>
-    where
-        T: std::cmp::PartialOrd + num::traits::Zero,
-    {
+    pub fn is_empty(&self) -> bool {
+        self.locations.is_empty()
+    }
+
+    /// Whether `point` lies within the bounds of the grid, regardless of
+    /// whether there is a cell at that position.
+    pub fn contains(&self, point: &Point<T>) -> bool {
+        self.bounds
+            .as_ref()
+            .is_some_and(|bounds| bounds.contains(point))
+    }
+
+    pub fn filter(&self, point: Point<T>) -> Option<Point<T>> {
         if self.contains(&point) {
             Some(point)
         } else {
@@ -478,6 +754,40 @@ where
         self.locations.get(point)
     }
 
+    pub fn get_mut(&mut self, point: &Point<T>) -> Option<&mut E> {
+        self.locations.get_mut(point)
+    }
+
+    /// Sets the cell at `point`, growing the bounds when needed. Returns the
+    /// previous value of the cell.
+    pub fn insert(&mut self, point: Point<T>, elem: E) -> Option<E> {
+        extend_bounds(&mut self.bounds, &point);
+        self.locations.insert(point, elem)
+    }
+
+    /// Removes the cell at `point`, shrinking the bounds when it was on their
+    /// edge.
+    pub fn remove(&mut self, point: &Point<T>) -> Option<E> {
+        let elem = self.locations.remove(point)?;
+        let on_edge = self.bounds.as_ref().is_some_and(|bounds| {
+            point.x == bounds.min_x
+                || point.x == bounds.max_x
+                || point.y == bounds.min_y
+                || point.y == bounds.max_y
+        });
+        if on_edge {
+            self.bounds = BBox::from_points(self.locations.keys().copied());
+        }
+        Some(elem)
+    }
+
+    pub fn entry(&mut self, point: Point<T>) -> Entry<'_, T, E> {
+        Entry {
+            entry: self.locations.entry(point),
+            bounds: &mut self.bounds,
+        }
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = (&Point<T>, &E)> {
         self.locations.iter()
     }
@@ -487,7 +797,7 @@ where
         point: &'a Point<T>,
     ) -> impl Iterator<Item = (Point<T>, &'a E)> + 'a
     where
-        T: num::traits::Zero + num::traits::One + num::traits::Signed + std::cmp::PartialOrd,
+        T: num::traits::Zero + num::traits::One + num::traits::Signed,
     {
         Direction::iter()
             .map(|direction| point.step(&direction))
@@ -497,17 +807,173 @@ where
             })
     }
 
-    pub fn bbox(&self) -> BBox<T>
+    /// Like [`Grid::neighbors`], but including the diagonal neighbours.
+    pub fn neighbors8<'a>(
+        &'a self,
+        point: &'a Point<T>,
+    ) -> impl Iterator<Item = (Point<T>, &'a E)> + 'a
+    where
+        T: num::traits::Zero + num::traits::One + num::traits::Signed,
+    {
+        point.neighbours8().filter_map(move |neighbor| {
+            self.filter(neighbor)
+                .and_then(|p| self.get(&p).map(|e| (p, e)))
+        })
+    }
+
+    /// The bounds of the grid, or `None` when it is empty.
+    pub fn bbox(&self) -> Option<BBox<T>> {
+        self.bounds.clone()
+    }
+}
+
+fn extend_bounds<T>(bounds: &mut Option<BBox<T>>, point: &Point<T>)
+where
+    T: PartialOrd + Copy,
+{
+    match bounds {
+        Some(bounds) => bounds.extend(point),
+        None => *bounds = Some(BBox::from_point(point)),
+    }
+}
+
+/// A single cell of a [`Grid`] that may or may not be present, see
+/// [`Grid::entry`]. Inserting through the entry keeps the bounds of the grid
+/// up to date.
+pub struct Entry<'a, T, E> {
+    entry: hash_map::Entry<'a, Point<T>, E>,
+    bounds: &'a mut Option<BBox<T>>,
+}
+
+impl<'a, T, E> Entry<'a, T, E>
+where
+    T: PartialOrd + Copy,
+{
+    pub fn point(&self) -> &Point<T> {
+        self.entry.key()
+    }
+
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut E),
+    {
+        Entry {
+            entry: self.entry.and_modify(f),
+            bounds: self.bounds,
+        }
+    }
+
+    pub fn or_insert(self, default: E) -> &'a mut E {
+        self.or_insert_with(|| default)
+    }
+
+    pub fn or_insert_with<F>(self, default: F) -> &'a mut E
+    where
+        F: FnOnce() -> E,
+    {
+        extend_bounds(self.bounds, self.entry.key());
+        self.entry.or_insert_with(default)
+    }
+
+    pub fn or_default(self) -> &'a mut E
+    where
+        E: Default,
+    {
+        self.or_insert_with(E::default)
+    }
+}
+
+/// Transformations that move the grid to the origin, so that e.g. a rotated
+/// grid spans `0..=width - 1` and `0..=height - 1`.
+impl<T, E> Grid<T, E>
+where
+    T: Hash + Eq + Copy + PartialOrd + num::traits::Num,
+    E: Clone,
+{
+    fn map_points<F>(&self, f: F) -> Self
     where
-        T: std::cmp::PartialOrd + Copy + num::traits::Zero,
+        F: Fn(&BBox<T>, &Point<T>) -> Point<T>,
     {
-        BBox::new(T::zero(), self.max_x, T::zero(), self.max_y)
+        match &self.bounds {
+            Some(bounds) => self
+                .locations
+                .iter()
+                .map(|(point, elem)| (f(bounds, point), elem.clone()))
+                .collect(),
+            None => Grid::default(),
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal.
+    pub fn transpose(&self) -> Self {
+        self.map_points(|b, p| Point::new(p.y - b.min_y, p.x - b.min_x))
+    }
+
+    /// Rotates the grid by 90 degrees clockwise.
+    pub fn rotate_cw(&self) -> Self {
+        self.map_points(|b, p| Point::new(b.max_y - p.y, p.x - b.min_x))
+    }
+
+    /// Rotates the grid by 90 degrees counter-clockwise.
+    pub fn rotate_ccw(&self) -> Self {
+        self.map_points(|b, p| Point::new(p.y - b.min_y, b.max_x - p.x))
+    }
+
+    /// Mirrors the grid horizontally, swapping left and right.
+    pub fn flip_h(&self) -> Self {
+        self.map_points(|b, p| Point::new(b.max_x - p.x, p.y - b.min_y))
+    }
+
+    /// Mirrors the grid vertically, swapping top and bottom.
+    pub fn flip_v(&self) -> Self {
+        self.map_points(|b, p| Point::new(p.x - b.min_x, b.max_y - p.y))
+    }
+
+    /// The cells within `bbox`, moved so that its top left corner is at the
+    /// origin, or `None` when `bbox` does not lie within the grid.
+    pub fn window(&self, bbox: &BBox<T>) -> Option<Self> {
+        let min = Point::new(bbox.min_x, bbox.min_y);
+        let max = Point::new(bbox.max_x, bbox.max_y);
+        if !(self.contains(&min) && self.contains(&max)) || min.x > max.x || min.y > max.y {
+            return None;
+        }
+
+        Some(
+            self.locations
+                .iter()
+                .filter(|(point, _)| bbox.contains(point))
+                .map(|(point, elem)| (Point::new(point.x - min.x, point.y - min.y), elem.clone()))
+                .collect(),
+        )
+    }
+
+    /// Repeats the grid `nx` times to the right and `ny` times downwards.
+    pub fn tile(&self, nx: usize, ny: usize) -> Self {
+        let Some(bounds) = &self.bounds else {
+            return Grid::default();
+        };
+        let offsets = |n: usize, size: T| {
+            iter::successors(Some(T::zero()), move |&o| Some(o + size))
+                .take(n)
+                .collect_vec()
+        };
+        let dxs = offsets(nx, bounds.max_x - bounds.min_x + T::one());
+        let dys = offsets(ny, bounds.max_y - bounds.min_y + T::one());
+
+        dys.iter()
+            .cartesian_product(&dxs)
+            .flat_map(|(&dy, &dx)| {
+                self.locations.iter().map(move |(point, elem)| {
+                    (Point::new(point.x + dx, point.y + dy), elem.clone())
+                })
+            })
+            .collect()
     }
 }
 
 impl<T, E> FromIterator<(Point<T>, E)> for Grid<T, E>
 where
-    T: num::traits::Zero + std::cmp::PartialOrd + Hash + Eq + Copy,
+    T: std::cmp::PartialOrd + Hash + Eq + Copy,
 {
     fn from_iter<I>(iter: I) -> Self
     where
@@ -515,13 +981,7 @@ where
     {
         iter.into_iter()
             .fold(Grid::emtpy(), |mut grid, (point, elem)| {
-                if grid.max_x < point.x {
-                    grid.max_x = point.x;
-                };
-                if grid.max_y < point.y {
-                    grid.max_y = point.y;
-                };
-                grid.locations.insert(point, elem);
+                grid.insert(point, elem);
                 grid
             })
     }
@@ -541,10 +1001,930 @@ where
             .flat_map(|(y, line)| {
                 line.chars().enumerate().map(move |(x, c)| {
                     let point: Point<T> = Point::new(x.try_into()?, y.try_into()?);
-                    let elem = E::try_from(c)?;
+                    let elem = E::try_from(c).map_err(|e| Error::from(e).at(y, x, line))?;
                     Ok((point, elem))
                 })
             })
             .try_collect()
     }
 }
+
+impl<T, E> Grid<T, E>
+where
+    T: TryFrom<usize> + num::traits::Zero + Hash + Eq + Copy + PartialOrd,
+    Error: From<<T as TryFrom<usize>>::Error>,
+{
+    /// Parses a grid in a single pass, mapping characters to cells with
+    /// `mapping`, except for the `markers`: their positions are returned
+    /// separately. Characters that are mapped to `None` and markers are left
+    /// out of the grid.
+    pub fn parse_with_markers<F>(
+        s: &str,
+        markers: &[char],
+        mut mapping: F,
+    ) -> Result<(Self, Markers<T>), Error>
+    where
+        F: FnMut(char) -> Result<Option<E>, Error>,
+    {
+        let mut found: Markers<T> = HashMap::new();
+        let mut cells = Vec::new();
+
+        for (y, line) in s.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                let point = Point::new(x.try_into()?, y.try_into()?);
+                if markers.contains(&c) {
+                    found.entry(c).or_default().push(point);
+                } else if let Some(elem) = mapping(c).map_err(|e| e.at(y, x, line))? {
+                    cells.push((point, elem));
+                }
+            }
+        }
+
+        Ok((cells.into_iter().collect(), found))
+    }
+}
+
+/// The points reachable from `seed` by orthogonal steps onto points for
+/// which `passable` holds, together with the number of steps needed to reach
+/// them. The seed itself is always included.
+pub fn flood_fill<T, F>(seed: Point<T>, mut passable: F) -> HashMap<Point<T>, usize>
+where
+    T: Copy + Hash + Eq + num::traits::Signed,
+    F: FnMut(&Point<T>) -> bool,
+{
+    let successors = |point: &Point<T>| point.neighbours().filter(|n| passable(n)).collect_vec();
+    crate::search::bfs([seed], successors, |_| false).distances
+}
+
+/// Splits the cells into regions of orthogonally connected cells with equal
+/// values.
+pub fn components<T, E, I>(cells: I) -> Vec<(E, Region<T>)>
+where
+    T: Copy + Hash + Eq + num::traits::Signed,
+    E: PartialEq,
+    I: IntoIterator<Item = (Point<T>, E)>,
+{
+    let mut unassigned: HashMap<Point<T>, E> = cells.into_iter().collect();
+    let mut regions = Vec::new();
+
+    while let Some(&seed) = unassigned.keys().next() {
+        let value = unassigned.remove(&seed).expect("the seed is unassigned");
+        let region: Region<T> = flood_fill(seed, |p| unassigned.get(p) == Some(&value))
+            .into_keys()
+            .collect();
+        region.points.iter().for_each(|p| {
+            unassigned.remove(p);
+        });
+        regions.push((value, region));
+    }
+
+    regions
+}
+
+/// A set of orthogonally connected points, e.g. a region found by
+/// [`components`].
+#[derive(Debug, Clone)]
+pub struct Region<T> {
+    pub points: HashSet<Point<T>>,
+}
+
+impl<T> Region<T>
+where
+    T: Copy + Hash + Eq + num::traits::Signed,
+{
+    pub fn area(&self) -> usize {
+        self.points.len()
+    }
+
+    /// The number of unit edges between a point of the region and one outside
+    /// of it.
+    pub fn perimeter(&self) -> usize {
+        self.points
+            .iter()
+            .flat_map(|p| p.neighbours().filter(|n| !self.points.contains(n)))
+            .count()
+    }
+
+    /// The number of straight sides of the outline of the region, including
+    /// those of any holes. This equals the number of corners.
+    pub fn sides(&self) -> usize {
+        self.points.iter().map(|p| self.corners_at(p)).sum()
+    }
+
+    /// The number of corners of the outline of the region at one of its
+    /// points.
+    pub fn corners_at(&self, point: &Point<T>) -> usize {
+        corners(point, &self.points)
+    }
+}
+
+impl<T> FromIterator<Point<T>> for Region<T>
+where
+    T: Hash + Eq,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Point<T>>,
+    {
+        Region {
+            points: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// The number of outline corners at `point`: outer corners where both
+/// neighbours around it are missing, and inner corners where both are present
+/// but the diagonal between them is not.
+fn corners<T>(point: &Point<T>, others: &HashSet<Point<T>>) -> usize
+where
+    T: Copy + Hash + Eq + num::traits::Signed,
+{
+    let outer = Direction::iter()
+        .filter(|d| {
+            !others.contains(&point.step(d)) && !others.contains(&point.step(&d.rotate_left()))
+        })
+        .count();
+
+    let inner = Direction::iter()
+        .filter(|&d| {
+            let right = d.rotate_right();
+            let diagonal = Direction8::from(d).rotate_right();
+            others.contains(&point.step(&d))
+                && others.contains(&point.step(&right))
+                && !others.contains(&point.step8(&diagonal))
+        })
+        .count();
+
+    inner + outer
+}
+
+/// A grid stored as a single row-major `Vec`, for maps where every cell within
+/// the bounds has a value. Points with negative or out-of-bounds coordinates
+/// are simply not part of the grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenseGrid<T, E> {
+    cells: Vec<E>,
+    width: usize,
+    height: usize,
+    coordinate: PhantomData<T>,
+}
+
+impl<T, E> DenseGrid<T, E>
+where
+    T: Copy + TryFrom<usize> + TryInto<usize>,
+{
+    pub fn new(width: usize, height: usize, fill: E) -> Self
+    where
+        E: Clone,
+    {
+        DenseGrid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+            coordinate: PhantomData,
+        }
+    }
+
+    /// Builds a grid from its cells in row-major order. A grid without cells
+    /// has a width of 0.
+    pub fn from_vec(width: usize, cells: Vec<E>) -> Result<Self, Error> {
+        if cells.is_empty() {
+            return Ok(DenseGrid {
+                cells,
+                width: 0,
+                height: 0,
+                coordinate: PhantomData,
+            });
+        }
+        if width == 0 || !cells.len().is_multiple_of(width) {
+            return Err(crate::error::parse_error(
+                &format!("{} cells do not fill rows of width {}", cells.len(), width),
+                "",
+            ));
+        }
+        let height = cells.len() / width;
+        Ok(DenseGrid {
+            cells,
+            width,
+            height,
+            coordinate: PhantomData,
+        })
+    }
+
+    /// Builds a grid from cells given by their points, which have to cover a
+    /// rectangle starting at the origin. Fails on negative coordinates and on
+    /// missing cells, such as those of a short row.
+    pub fn from_cells<I>(cells: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = (Point<T>, E)>,
+        T: Debug,
+    {
+        let cells = cells
+            .into_iter()
+            .map(
+                |(point, elem)| match (point.x.try_into(), point.y.try_into()) {
+                    (Ok(x), Ok(y)) => Ok(((x, y), elem)),
+                    _ => Err(crate::error::parse_error(
+                        "negative coordinates can not be part of a dense grid",
+                        &format!("{:?}", point),
+                    )),
+                },
+            )
+            .collect::<Result<Vec<((usize, usize), E)>, Error>>()?;
+
+        let width = cells.iter().map(|((x, _), _)| x + 1).max().unwrap_or(0);
+        let height = cells.iter().map(|((_, y), _)| y + 1).max().unwrap_or(0);
+
+        let mut slots: Vec<Option<E>> = std::iter::repeat_with(|| None)
+            .take(width * height)
+            .collect();
+        for ((x, y), elem) in cells {
+            slots[y * width + x] = Some(elem);
+        }
+
+        let cells = slots
+            .into_iter()
+            .enumerate()
+            .map(|(i, elem)| {
+                elem.ok_or_else(|| {
+                    crate::error::parse_error(
+                        "missing cell of a dense grid",
+                        &format!("({}, {})", i % width, i / width),
+                    )
+                })
+            })
+            .collect::<Result<Vec<E>, Error>>()?;
+        DenseGrid::from_vec(width, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    #[inline]
+    fn index(&self, point: &Point<T>) -> Option<usize> {
+        let x: usize = point.x.try_into().ok()?;
+        let y: usize = point.y.try_into().ok()?;
+        if x < self.width && y < self.height {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn point(&self, index: usize) -> Point<T>
+    where
+        <T as TryFrom<usize>>::Error: Debug,
+    {
+        Point::new(
+            T::try_from(index % self.width).unwrap(),
+            T::try_from(index / self.width).unwrap(),
+        )
+    }
+
+    #[inline]
+    pub fn contains(&self, point: &Point<T>) -> bool {
+        self.index(point).is_some()
+    }
+
+    #[inline]
+    pub fn get(&self, point: &Point<T>) -> Option<&E> {
+        self.index(point).map(|i| &self.cells[i])
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, point: &Point<T>) -> Option<&mut E> {
+        self.index(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Replaces the value of a cell, returning the old value, or `None` when
+    /// the point is outside of the grid.
+    pub fn set(&mut self, point: &Point<T>, elem: E) -> Option<E> {
+        self.get_mut(point)
+            .map(|cell| std::mem::replace(cell, elem))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[E]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &E> + '_> {
+        (x < self.width).then(|| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[E]> + '_ {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point<T>, &E)> + '_
+    where
+        <T as TryFrom<usize>>::Error: Debug,
+    {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, elem)| (self.point(i), elem))
+    }
+
+    pub fn neighbors(&self, point: &Point<T>) -> impl Iterator<Item = (Point<T>, &E)> + '_
+    where
+        T: num::traits::Zero + num::traits::One + num::traits::Signed,
+    {
+        let point = *point;
+        Direction::iter()
+            .map(move |direction| point.step(&direction))
+            .filter_map(|neighbor| self.get(&neighbor).map(|e| (neighbor, e)))
+    }
+
+    /// Like [`DenseGrid::neighbors`], but including the diagonal neighbours.
+    pub fn neighbors8(&self, point: &Point<T>) -> impl Iterator<Item = (Point<T>, &E)> + '_
+    where
+        T: num::traits::Zero + num::traits::One + num::traits::Signed,
+    {
+        let point = *point;
+        Direction8::iter()
+            .map(move |direction| point.step8(&direction))
+            .filter_map(|neighbor| self.get(&neighbor).map(|e| (neighbor, e)))
+    }
+
+    /// The bounds of the grid, or `None` when it has no cells.
+    pub fn bbox(&self) -> Option<BBox<T>>
+    where
+        T: num::traits::Zero + PartialOrd,
+        <T as TryFrom<usize>>::Error: Debug,
+    {
+        (!self.cells.is_empty()).then(|| {
+            BBox::new(
+                T::zero(),
+                T::try_from(self.width - 1).unwrap(),
+                T::zero(),
+                T::try_from(self.height - 1).unwrap(),
+            )
+        })
+    }
+}
+
+impl<T, E> DenseGrid<T, E>
+where
+    E: Clone,
+    T: Copy + TryFrom<usize> + TryInto<usize>,
+{
+    /// Builds a grid by calling `f` with the coordinates of every cell.
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> E,
+    {
+        DenseGrid {
+            cells: (0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .map(|(x, y)| f(x, y))
+                .collect(),
+            width,
+            height,
+            coordinate: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn cell(&self, x: usize, y: usize) -> E {
+        self.cells[y * self.width + x].clone()
+    }
+
+    /// Mirrors the grid along its main diagonal.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| self.cell(y, x))
+    }
+
+    /// Rotates the grid by 90 degrees clockwise.
+    pub fn rotate_cw(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| {
+            self.cell(y, self.height - 1 - x)
+        })
+    }
+
+    /// Rotates the grid by 90 degrees counter-clockwise.
+    pub fn rotate_ccw(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| {
+            self.cell(self.width - 1 - y, x)
+        })
+    }
+
+    /// Mirrors the grid horizontally, swapping left and right.
+    pub fn flip_h(&self) -> Self {
+        Self::from_fn(self.width, self.height, |x, y| {
+            self.cell(self.width - 1 - x, y)
+        })
+    }
+
+    /// Mirrors the grid vertically, swapping top and bottom.
+    pub fn flip_v(&self) -> Self {
+        Self::from_fn(self.width, self.height, |x, y| {
+            self.cell(x, self.height - 1 - y)
+        })
+    }
+
+    /// A copy of the cells within `bbox`, or `None` when `bbox` does not lie
+    /// within the grid.
+    pub fn window(&self, bbox: &BBox<T>) -> Option<Self> {
+        let min_x: usize = bbox.min_x.try_into().ok()?;
+        let max_x: usize = bbox.max_x.try_into().ok()?;
+        let min_y: usize = bbox.min_y.try_into().ok()?;
+        let max_y: usize = bbox.max_y.try_into().ok()?;
+        if min_x > max_x || min_y > max_y || max_x >= self.width || max_y >= self.height {
+            return None;
+        }
+
+        Some(Self::from_fn(
+            max_x - min_x + 1,
+            max_y - min_y + 1,
+            |x, y| self.cell(min_x + x, min_y + y),
+        ))
+    }
+
+    /// Repeats the grid `nx` times horizontally and `ny` times vertically.
+    pub fn tile(&self, nx: usize, ny: usize) -> Self {
+        Self::from_fn(self.width * nx, self.height * ny, |x, y| {
+            self.cell(x % self.width, y % self.height)
+        })
+    }
+}
+
+impl<T, E> std::ops::Index<Point<T>> for DenseGrid<T, E>
+where
+    T: Copy + TryFrom<usize> + TryInto<usize> + Debug,
+{
+    type Output = E;
+
+    fn index(&self, point: Point<T>) -> &E {
+        self.get(&point)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", point))
+    }
+}
+
+impl<T, E> std::ops::IndexMut<Point<T>> for DenseGrid<T, E>
+where
+    T: Copy + TryFrom<usize> + TryInto<usize> + Debug,
+{
+    fn index_mut(&mut self, point: Point<T>) -> &mut E {
+        self.get_mut(&point)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", point))
+    }
+}
+
+impl<T, E> FromStr for DenseGrid<T, E>
+where
+    E: TryFrom<char>,
+    T: Copy + TryFrom<usize> + TryInto<usize>,
+    Error: From<<E as TryFrom<char>>::Error>,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DenseGrid::parse_with(s, |c| E::try_from(c).map_err(Error::from))
+    }
+}
+
+impl<T, E> DenseGrid<T, E>
+where
+    T: Copy + TryFrom<usize> + TryInto<usize>,
+{
+    /// Parses a grid, mapping characters to cells with `mapping`. All rows
+    /// need to have the same length.
+    pub fn parse_with<F>(s: &str, mut mapping: F) -> Result<Self, Error>
+    where
+        F: FnMut(char) -> Result<E, Error>,
+    {
+        let (width, cells) = parse_cells(s, |_, _, c| mapping(c))?;
+        DenseGrid::from_vec(width, cells)
+    }
+}
+
+impl<T, E> DenseGrid<T, E>
+where
+    E: Default,
+    T: Copy + TryFrom<usize> + TryInto<usize>,
+    Error: From<<T as TryFrom<usize>>::Error>,
+{
+    /// Parses a grid in a single pass, mapping characters to cells with
+    /// `mapping`, except for the `markers`: their positions are returned
+    /// separately and their cells are left at the default value.
+    pub fn parse_with_markers<F>(
+        s: &str,
+        markers: &[char],
+        mut mapping: F,
+    ) -> Result<(Self, Markers<T>), Error>
+    where
+        F: FnMut(char) -> Result<E, Error>,
+    {
+        let mut found: Markers<T> = HashMap::new();
+        let (width, cells) = parse_cells(s, |x, y, c| {
+            if markers.contains(&c) {
+                let point = Point::new(x.try_into()?, y.try_into()?);
+                found.entry(c).or_default().push(point);
+                Ok(E::default())
+            } else {
+                mapping(c)
+            }
+        })?;
+
+        Ok((DenseGrid::from_vec(width, cells)?, found))
+    }
+}
+
+/// The cells of a rectangular block of characters in row-major order, together
+/// with its width. Errors point at the offending character.
+fn parse_cells<E, F>(s: &str, mut cell: F) -> Result<(usize, Vec<E>), Error>
+where
+    F: FnMut(usize, usize, char) -> Result<E, Error>,
+{
+    let width = s.lines().next().map_or(0, |line| line.chars().count());
+    let mut cells = Vec::with_capacity(width * s.lines().count());
+
+    for (y, line) in s.lines().enumerate() {
+        let mut length = 0;
+        for (x, c) in line.chars().enumerate() {
+            cells.push(cell(x, y, c).map_err(|e| e.at(y, x, line))?);
+            length += 1;
+        }
+        if length != width {
+            return Err(crate::error::parse_error_at(
+                &format!("expected a row of {} cells", width),
+                y,
+                length.min(width),
+                line,
+            ));
+        }
+    }
+
+    Ok((width, cells))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dense_grid() {
+        let grid: DenseGrid<i16, char> =
+            DenseGrid::from_vec(3, "abcdef".chars().collect()).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(&Point::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(&Point::new(3, 0)), None);
+        assert_eq!(grid.get(&Point::new(-1, 0)), None);
+        assert_eq!(grid[Point::new(1, 0)], 'b');
+
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.column(2).unwrap().collect::<String>(), "cf");
+        assert!(grid.column(3).is_none());
+
+        let neighbors = grid.neighbors(&Point::new(0, 0)).collect_vec();
+        assert_eq!(
+            neighbors,
+            vec![(Point::new(0, 1), &'d'), (Point::new(1, 0), &'b')]
+        );
+
+        let (point, elem) = grid.iter().nth(4).unwrap();
+        assert_eq!((point, elem), (Point::new(1, 1), &'e'));
+        assert!(DenseGrid::<i16, char>::from_vec(4, "abcdef".chars().collect()).is_err());
+    }
+
+    #[test]
+    fn test_dense_grid_from() {
+        let cells = |points: &[(i32, i32)]| {
+            points
+                .iter()
+                .map(|&(x, y)| (Point::new(x, y), x + 10 * y))
+                .collect_vec()
+        };
+        let grid: DenseGrid<i32, i32> =
+            DenseGrid::from_cells(cells(&[(1, 1), (0, 0), (1, 0), (0, 1), (2, 0), (2, 1)]))
+                .unwrap();
+        assert_eq!(
+            grid.rows().collect_vec(),
+            vec![&[0, 1, 2][..], &[10, 11, 12][..]]
+        );
+        assert!(DenseGrid::<i32, i32>::from_cells(cells(&[(0, 0), (1, 0), (0, 1)])).is_err());
+        assert!(DenseGrid::<i32, i32>::from_cells(cells(&[(-1, 0), (0, 0)])).is_err());
+        assert_eq!(grid.bbox(), Some(BBox::new(0, 2, 0, 1)));
+        let empty = DenseGrid::<i32, i32>::from_cells(vec![]).unwrap();
+        assert_eq!((empty.width(), empty.height(), empty.bbox()), (0, 0, None));
+        assert_eq!(
+            DenseGrid::<i32, char>::from_str("").unwrap().rows().count(),
+            0
+        );
+
+        let digits = |c: char| {
+            c.to_digit(10)
+                .ok_or_else(|| crate::error::parse_error("invalid digit", &c.to_string()))
+        };
+        let grid: DenseGrid<i32, u32> = DenseGrid::parse_with("01\n23", digits).unwrap();
+        assert_eq!(grid[Point::new(1, 1)], 3);
+        assert!(matches!(
+            DenseGrid::<i32, u32>::parse_with("01\n2", digits),
+            Err(Error::ParseAt { line: 2, .. })
+        ));
+
+        #[derive(Debug, PartialEq)]
+        struct Cell(char);
+        impl TryFrom<char> for Cell {
+            type Error = Error;
+            fn try_from(c: char) -> Result<Self, Error> {
+                match c {
+                    '#' | '.' => Ok(Cell(c)),
+                    _ => Err(crate::error::parse_error("invalid cell", &c.to_string())),
+                }
+            }
+        }
+
+        let grid: DenseGrid<i32, Cell> = "#.\n.#".parse().unwrap();
+        assert_eq!(grid[Point::new(1, 1)], Cell('#'));
+        assert!(matches!(
+            "#.\n.x".parse::<DenseGrid<i32, Cell>>(),
+            Err(Error::ParseAt {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+        assert!(matches!(
+            "#.\n.".parse::<DenseGrid<i32, Cell>>(),
+            Err(Error::ParseAt { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_with_markers() {
+        let input = "S.#\n.#E\n..S";
+        let mapping = |c| match c {
+            '.' => Ok(false),
+            '#' => Ok(true),
+            _ => Err(crate::error::parse_error("invalid cell", &c.to_string())),
+        };
+
+        let (grid, markers) =
+            DenseGrid::<i16, bool>::parse_with_markers(input, &['S', 'E'], mapping).unwrap();
+        assert_eq!(grid.iter().filter(|(_, &wall)| wall).count(), 2);
+        assert!(!grid[Point::new(2, 1)]);
+        assert_eq!(markers[&'S'], vec![Point::new(0, 0), Point::new(2, 2)]);
+        assert_eq!(markers[&'E'], vec![Point::new(2, 1)]);
+        assert_eq!(single_marker(&markers, 'E').unwrap(), Point::new(2, 1));
+        assert!(single_marker(&markers, 'S').is_err());
+        assert!(single_marker(&markers, 'X').is_err());
+
+        assert!(matches!(
+            DenseGrid::<i16, bool>::parse_with_markers(input, &['S'], mapping),
+            Err(Error::ParseAt {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+
+        let (grid, markers) = Grid::<i16, ()>::parse_with_markers(input, &['E'], |c| {
+            mapping(c).map(|wall| wall.then_some(())).or(Ok(None))
+        })
+        .unwrap();
+        assert_eq!(grid.iter().count(), 2);
+        assert!(grid.get(&Point::new(2, 1)).is_none());
+        assert_eq!(markers[&'E'], vec![Point::new(2, 1)]);
+    }
+
+    #[test]
+    fn test_direction8() {
+        assert_eq!(Direction8::iter().count(), 8);
+        assert_eq!(Direction8::diagonals().count(), 4);
+        assert_eq!(Direction8::NE.opposite(), Direction8::SW);
+        assert_eq!(Direction8::N.rotate_left(), Direction8::NW);
+        assert_eq!(Direction8::NW.rotate_right(), Direction8::N);
+        assert_eq!(
+            Direction8::between(Direction::Left, Direction::Down),
+            Some(Direction8::SW)
+        );
+        assert_eq!(Direction8::between(Direction::Left, Direction::Right), None);
+        assert!(Direction::iter().all(|d| {
+            let d8 = Direction8::from(d);
+            !d8.is_diagonal() && d8.d::<i32>() == d.d::<i32>()
+        }));
+
+        let neighbours = Point::new(0, 0).neighbours8().collect_vec();
+        assert_eq!(neighbours.len(), 8);
+        assert_eq!(neighbours[1], Point::new(1, -1));
+
+        assert_eq!(
+            ray(Point::new(1, 1), Direction8::SE, 3).collect_vec(),
+            vec![Point::new(1, 1), Point::new(2, 2), Point::new(3, 3)]
+        );
+        assert_eq!(ray(Point::new(1, 1), Direction8::W, 0).count(), 0);
+    }
+
+    #[test]
+    fn test_neighbors8() {
+        let grid: DenseGrid<i16, char> =
+            DenseGrid::from_vec(3, "abcdef".chars().collect()).unwrap();
+        let neighbors = grid
+            .neighbors8(&Point::new(0, 0))
+            .map(|(_, &c)| c)
+            .collect::<String>();
+        assert_eq!(neighbors, "bed");
+
+        let grid: Grid<i16, char> = grid.iter().map(|(p, &c)| (p, c)).collect();
+        let mut neighbors = grid
+            .neighbors8(&Point::new(1, 1))
+            .map(|(_, &c)| c)
+            .collect_vec();
+        neighbors.sort();
+        assert_eq!(neighbors, vec!['a', 'b', 'c', 'd', 'f']);
+    }
+
+    fn dense(s: &str) -> DenseGrid<i32, char> {
+        s.parse().unwrap()
+    }
+
+    fn sparse(s: &str) -> Grid<i32, char> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_dense_transformations() {
+        let grid = dense("abc\ndef");
+
+        assert_eq!(grid.transpose(), dense("ad\nbe\ncf"));
+        assert_eq!(grid.rotate_cw(), dense("da\neb\nfc"));
+        assert_eq!(grid.rotate_ccw(), dense("cf\nbe\nad"));
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.rotate_cw().rotate_cw(), grid.flip_h().flip_v());
+        assert_eq!(grid.flip_h(), dense("cba\nfed"));
+        assert_eq!(grid.flip_v(), dense("def\nabc"));
+
+        assert_eq!(grid.window(&BBox::new(1, 2, 0, 1)), Some(dense("bc\nef")));
+        assert_eq!(grid.window(&BBox::new(2, 2, 1, 1)), Some(dense("f")));
+        assert_eq!(grid.window(&BBox::new(1, 3, 0, 1)), None);
+        assert_eq!(grid.window(&BBox::new(-1, 1, 0, 1)), None);
+
+        assert_eq!(
+            grid.tile(2, 3),
+            dense("abcabc\ndefdef\nabcabc\ndefdef\nabcabc\ndefdef")
+        );
+        assert_eq!(grid.tile(0, 2).width(), 0);
+    }
+
+    #[test]
+    fn test_sparse_transformations() {
+        let grid = sparse("abc\ndef");
+
+        assert_eq!(grid.transpose(), sparse("ad\nbe\ncf"));
+        assert_eq!(grid.rotate_cw(), sparse("da\neb\nfc"));
+        assert_eq!(grid.rotate_ccw(), sparse("cf\nbe\nad"));
+        assert_eq!(grid.flip_h(), sparse("cba\nfed"));
+        assert_eq!(grid.flip_v(), sparse("def\nabc"));
+
+        assert_eq!(grid.window(&BBox::new(1, 2, 0, 1)), Some(sparse("bc\nef")));
+        assert!(grid.window(&BBox::new(0, 3, 0, 1)).is_none());
+
+        assert_eq!(grid.tile(2, 1), sparse("abcabc\ndefdef"));
+        let tiled = grid.tile(1, 2);
+        assert_eq!(tiled.bbox(), Some(BBox::new(0, 2, 0, 3)));
+        assert_eq!(tiled.get(&Point::new(1, 3)), Some(&'e'));
+
+        let moved: Grid<i32, char> = grid
+            .iter()
+            .map(|(p, &c)| (Point::new(p.x - 5, p.y - 7), c))
+            .collect();
+        assert_eq!(moved.rotate_cw(), grid.rotate_cw());
+        assert_eq!(moved.tile(2, 1).bbox(), Some(BBox::new(-5, 0, -7, -6)));
+    }
+
+    #[test]
+    fn test_grid_bounds() {
+        let mut grid: Grid<i32, u8> = [(Point::new(-2, 3), 1), (Point::new(1, -1), 2)]
+            .into_iter()
+            .collect();
+        assert_eq!(grid.bbox(), Some(BBox::new(-2, 1, -1, 3)));
+        assert!(grid.contains(&Point::new(-1, 0)));
+        assert!(!grid.contains(&Point::new(2, 0)));
+
+        assert_eq!(grid.insert(Point::new(4, 0), 3), None);
+        assert_eq!(grid.insert(Point::new(4, 0), 4), Some(3));
+        assert_eq!(grid.bbox(), Some(BBox::new(-2, 4, -1, 3)));
+
+        *grid.get_mut(&Point::new(1, -1)).unwrap() += 10;
+        assert_eq!(grid.get(&Point::new(1, -1)), Some(&12));
+
+        *grid.entry(Point::new(0, -5)).or_default() += 1;
+        grid.entry(Point::new(0, -5)).and_modify(|e| *e += 1);
+        assert_eq!(grid.get(&Point::new(0, -5)), Some(&2));
+        assert_eq!(grid.bbox(), Some(BBox::new(-2, 4, -5, 3)));
+
+        assert_eq!(grid.remove(&Point::new(0, -5)), Some(2));
+        assert_eq!(grid.remove(&Point::new(-2, 3)), Some(1));
+        assert_eq!(grid.remove(&Point::new(-2, 3)), None);
+        assert_eq!(grid.bbox(), Some(BBox::new(1, 4, -1, 0)));
+
+        let point = Point::new(1, 0);
+        let neighbors = grid.neighbors(&point).collect_vec();
+        assert_eq!(neighbors, vec![(Point::new(1, -1), &12)]);
+
+        grid.remove(&Point::new(1, -1));
+        grid.remove(&Point::new(4, 0));
+        assert!(grid.is_empty());
+        assert_eq!(grid.bbox(), None);
+        assert!(!grid.contains(&Point::new(0, 0)));
+    }
+
+    #[test]
+    fn test_bbox() {
+        let bbox = BBox::new(-2, 1, 3, 5);
+        assert_eq!(bbox.x_iter().collect_vec(), vec![-2, -1, 0, 1]);
+        assert_eq!(bbox.y_iter().collect_vec(), vec![3, 4, 5]);
+        assert_eq!(bbox.rows().next().unwrap().next(), Some(Point::new(-2, 3)));
+        assert_eq!(
+            bbox.render(|p| if p.x == p.y - 5 { '#' } else { '.' }),
+            "#...\n.#..\n..#."
+        );
+        assert_eq!((bbox.width(), bbox.height(), bbox.area()), (4, 3, 12));
+
+        let other = BBox::new(0, 6, -1, 3);
+        assert_eq!(bbox.intersection(&other), Some(BBox::new(0, 1, 3, 3)));
+        assert_eq!(bbox.intersection(&BBox::new(2, 3, 3, 5)), None);
+        assert_eq!(bbox.union(&other), BBox::new(-2, 6, -1, 5));
+        assert_eq!(bbox.expand(1), BBox::new(-3, 2, 2, 6));
+        assert_eq!(
+            bbox.corners(),
+            [
+                Point::new(-2, 3),
+                Point::new(1, 3),
+                Point::new(1, 5),
+                Point::new(-2, 5)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_quadrants() {
+        assert_eq!(
+            BBox::new(0, 10, 0, 6).quadrants(),
+            Some([
+                BBox::new(0, 4, 0, 2),
+                BBox::new(6, 10, 0, 2),
+                BBox::new(0, 4, 4, 6),
+                BBox::new(6, 10, 4, 6)
+            ])
+        );
+        assert_eq!(
+            BBox::new(-2, 1, 1, 2).quadrants(),
+            Some([
+                BBox::new(-2, -1, 1, 1),
+                BBox::new(0, 1, 1, 1),
+                BBox::new(-2, -1, 2, 2),
+                BBox::new(0, 1, 2, 2)
+            ])
+        );
+        assert_eq!(
+            BBox::<u8>::new(0, 2, 0, 2).quadrants(),
+            Some([
+                BBox::new(0, 0, 0, 0),
+                BBox::new(2, 2, 0, 0),
+                BBox::new(0, 0, 2, 2),
+                BBox::new(2, 2, 2, 2)
+            ])
+        );
+
+        assert_eq!(BBox::<u8>::new(3, 3, 0, 5).quadrants(), None);
+        assert_eq!(BBox::<u8>::new(0, 5, 3, 3).quadrants(), None);
+        assert_eq!(BBox::<i8>::new(1, 1, 0, 5).quadrants(), None);
+        assert_eq!(BBox::<i8>::new(4, 1, 0, 5).quadrants(), None);
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let grid: DenseGrid<i32, char> = "..#\n#..\n.#.".parse().unwrap();
+        let reached = flood_fill(Point::new(0, 0), |p| grid.get(p) == Some(&'.'));
+        assert_eq!(reached.len(), 5);
+        assert_eq!(reached[&Point::new(2, 2)], 4);
+        assert!(!reached.contains_key(&Point::new(0, 2)));
+    }
+
+    #[test]
+    fn test_components() {
+        let grid: DenseGrid<i32, char> = "AAB\nBAB\nBBA".parse().unwrap();
+        let mut regions = components(grid.iter())
+            .into_iter()
+            .map(|(&c, region)| (c, region.area(), region.perimeter(), region.sides()))
+            .collect_vec();
+        regions.sort();
+        assert_eq!(
+            regions,
+            vec![
+                ('A', 1, 4, 4),
+                ('A', 3, 8, 6),
+                ('B', 2, 6, 4),
+                ('B', 3, 8, 6)
+            ]
+        );
+    }
+}
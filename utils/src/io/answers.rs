@@ -0,0 +1,100 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use crate::error::Result;
+use crate::puzzle::Part;
+
+/// Known answers, stored as TOML keyed by day, input name and part:
+///
+/// ```toml
+/// [day_01.puzzle]
+/// part_1 = "3714264"
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AnswerStore {
+    path: PathBuf,
+    answers: BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>,
+}
+
+impl AnswerStore {
+    /// Loads the store from `path`, starting from an empty store if the file
+    /// does not exist yet.
+    pub fn load<P>(path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref().to_path_buf();
+        let answers = match fs::read_to_string(&path) {
+            Ok(content) => toml::from_str(&content)?,
+            Err(e) if e.kind() == ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        tracing::debug!(path = %path.display(), "loaded answers for {} days", answers.len());
+        Ok(AnswerStore { path, answers })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        tracing::debug!(path = %self.path.display(), "saving answers");
+        fs::write(&self.path, toml::to_string(&self.answers)?)?;
+        Ok(())
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get(&self, day: u8, input: &str, part: Part) -> Option<&str> {
+        self.answers
+            .get(&day_key(day))
+            .and_then(|inputs| inputs.get(input))
+            .and_then(|parts| parts.get(&part_key(part)))
+            .map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u8, input: &str, part: Part, answer: String) {
+        self.answers
+            .entry(day_key(day))
+            .or_default()
+            .entry(input.to_owned())
+            .or_default()
+            .insert(part_key(part), answer);
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("day_{:02}", day)
+}
+
+fn part_key(part: Part) -> String {
+    format!("part_{}", part.number())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let path = std::env::temp_dir().join(format!("answers-{}.toml", std::process::id()));
+
+        let mut store = AnswerStore::load(&path).unwrap();
+        assert_eq!(store.get(1, "puzzle", Part::One), None);
+
+        store.insert(1, "puzzle", Part::One, "11".to_owned());
+        store.insert(17, "example", Part::Two, "4,6,3".to_owned());
+        store.save().unwrap();
+
+        let store = AnswerStore::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(store.get(1, "puzzle", Part::One), Some("11"));
+        assert_eq!(store.get(1, "puzzle", Part::Two), None);
+        assert_eq!(store.get(17, "example", Part::Two), Some("4,6,3"));
+        assert_eq!(store.get(17, "puzzle", Part::Two), None);
+    }
+}
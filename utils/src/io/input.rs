@@ -1,55 +1,164 @@
+use crate::config::Args;
 use crate::error::{Error, Result};
 use err_into::ErrorInto;
 use itertools::Itertools;
-use std::result::Result as StdResult;
 use std::{
-    io::{self, Read},
+    env,
+    io::{self, IsTerminal, Read},
+    path::{Path, PathBuf},
     str::FromStr,
 };
 
-pub fn read_input() -> StdResult<String, io::Error> {
-    let input = match std::env::args().nth(1) {
-        Some(file) if !file.starts_with('-') => {
-            tracing::debug!(file = file, "Reading input from file");
-            std::fs::read_to_string(file).err_into()
+/// Environment variable pointing to a directory holding `dayNN.txt` inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where the input of a run is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Picks the input for `day`: the file given on the command line, stdin
+    /// when that is `-`, or else the first existing conventional location
+    /// (see [`input_candidates`]). Stdin is only read without `-` when it is
+    /// piped and none of those locations exist, so a closed or redirected
+    /// stdin never shadows an input file.
+    pub fn locate(day: u8, args: &Args) -> Result<Self> {
+        match &args.input {
+            Some(path) if path.as_os_str() == "-" => Ok(InputSource::Stdin),
+            Some(path) => Ok(InputSource::File(path.clone())),
+            None => {
+                let candidates = input_candidates(day, args.example);
+                match candidates.iter().find(|path| path.is_file()) {
+                    Some(path) => Ok(InputSource::File(path.clone())),
+                    None if !io::stdin().is_terminal() => Ok(InputSource::Stdin),
+                    None => Err(Error::InputNotFound(candidates)),
+                }
+            }
         }
-        _ => {
-            tracing::debug!("Reading input from stdin");
-            let mut input = String::new();
-            let stdin = std::io::stdin();
-            let mut handle = stdin.lock();
-            handle.read_to_string(&mut input)?;
-            Ok(input)
+    }
+
+    /// A short name for the input, used to tell answers for different inputs
+    /// of the same day apart: the file stem, or `stdin`.
+    pub fn name(&self) -> String {
+        match self {
+            InputSource::File(path) => path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.display().to_string()),
+            InputSource::Stdin => "stdin".to_owned(),
         }
-    };
+    }
+
+    pub fn read(&self) -> Result<String> {
+        let input = match self {
+            InputSource::File(path) => {
+                tracing::debug!(file = %path.display(), "Reading input from file");
+                std::fs::read_to_string(path).map_err(|e| {
+                    Error::IO(io::Error::new(
+                        e.kind(),
+                        format!("{}: {}", path.display(), e),
+                    ))
+                })?
+            }
+            InputSource::Stdin => {
+                tracing::debug!("Reading input from stdin");
+                let mut input = String::new();
+                let stdin = std::io::stdin();
+                let mut handle = stdin.lock();
+                handle.read_to_string(&mut input)?;
+                if input.is_empty() {
+                    return Err(Error::IO(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "stdin: no input",
+                    )));
+                }
+                input
+            }
+        };
 
-    if tracing::enabled!(tracing::Level::DEBUG) {
-        if let Ok(input) = input.as_ref() {
+        if tracing::enabled!(tracing::Level::DEBUG) {
             tracing::debug!(
                 "Read {} bytes in {} lines",
                 input.len(),
                 input.lines().count()
             );
         }
+
+        Ok(input)
     }
+}
+
+/// The conventional locations of the input of `day`, in order of preference:
+///
+/// 1. `$AOC_INPUT_DIR/dayNN.txt`
+/// 2. `inputs/dayNN.txt`
+/// 3. `day_NN/input/puzzle.txt`
+/// 4. `input/puzzle.txt`, when running from within the day's crate
+///
+/// With `example` set, `dayNN_example.txt` and `example.txt` are used instead.
+pub fn input_candidates(day: u8, example: bool) -> Vec<PathBuf> {
+    let flat = if example {
+        format!("day{:02}_example.txt", day)
+    } else {
+        format!("day{:02}.txt", day)
+    };
+    let file = if example { "example.txt" } else { "puzzle.txt" };
+    let crate_dir = format!("day_{:02}", day);
 
-    input
+    let mut candidates = Vec::new();
+    if let Some(dir) = env::var_os(INPUT_DIR_VAR) {
+        candidates.push(Path::new(&dir).join(&flat));
+    }
+    candidates.push(Path::new("inputs").join(&flat));
+    candidates.push(Path::new(&crate_dir).join("input").join(file));
+    candidates.push(Path::new("input").join(file));
+
+    candidates
+}
+
+pub fn read_input(day: u8, args: &Args) -> Result<String> {
+    InputSource::locate(day, args)?.read()
 }
 
-pub fn read_lines<I>() -> StdResult<I, io::Error>
+pub fn read_lines<I>(day: u8, args: &Args) -> Result<I>
 where
     I: FromIterator<String>,
 {
-    read_input().map(|input| input.lines().map(str::to_string).collect())
+    read_input(day, args).map(|input| input.lines().map(str::to_string).collect())
 }
 
-pub fn parse_lines<T, I>() -> Result<I>
+pub fn parse_lines<T, I>(day: u8, args: &Args) -> Result<I>
 where
     T: FromStr,
     I: FromIterator<T>,
     T::Err: Into<Error>,
 {
-    read_input()
-        .err_into()
+    read_input(day, args)
         .and_then(|input| input.lines().map(|l| l.parse().err_into()).try_collect())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_candidates() {
+        let candidates = input_candidates(7, false);
+        assert!(candidates.contains(&PathBuf::from("inputs/day07.txt")));
+        assert!(candidates.contains(&PathBuf::from("day_07/input/puzzle.txt")));
+
+        let candidates = input_candidates(7, true);
+        assert!(candidates.contains(&PathBuf::from("inputs/day07_example.txt")));
+        assert!(candidates.contains(&PathBuf::from("day_07/input/example.txt")));
+        assert_eq!(candidates.last(), Some(&PathBuf::from("input/example.txt")));
+    }
+
+    #[test]
+    fn test_input_not_found() {
+        let error = Error::InputNotFound(vec![PathBuf::from("a.txt"), PathBuf::from("b.txt")]);
+        assert_eq!(error.to_string(), "No input found, tried: a.txt, b.txt");
+    }
+}
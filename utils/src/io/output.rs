@@ -1,4 +1,7 @@
+use std::{fmt::Display, path::Path, str::FromStr, time::Duration};
+
 use console::style;
+use serde::Serialize;
 use time::macros::format_description;
 use tracing::level_filters::LevelFilter;
 use tracing_subscriber::{
@@ -6,6 +9,10 @@ use tracing_subscriber::{
     EnvFilter,
 };
 
+use crate::error::{parse_error, Error};
+use crate::io::answers::AnswerStore;
+use crate::puzzle::Part;
+
 pub fn init_tracing() {
     let filter = EnvFilter::builder()
         .with_default_directive(LevelFilter::ERROR.into())
@@ -71,3 +78,342 @@ where
 {
     print_solution("Part 2", solution);
 }
+
+pub fn print_duration(prefix: &str, elapsed: Duration) {
+    if tracing::enabled!(tracing::Level::INFO) {
+        tracing::info!("{} took {:.2?}", prefix, elapsed);
+    } else {
+        eprintln!(
+            "{}",
+            style(format!("{} took {:.2?}", prefix, elapsed)).dim()
+        );
+    }
+}
+
+/// How the answers of a run are reported, as selected by `--format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// Styled answers on stdout and timings on stderr.
+    #[default]
+    Pretty,
+    /// Only the answers, one per line, without any styling.
+    Plain,
+    /// A single JSON object with the answers, errors and timings of the run.
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> crate::error::Result<Self> {
+        match s {
+            "pretty" => Ok(OutputFormat::Pretty),
+            "plain" => Ok(OutputFormat::Plain),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(parse_error("expected one of json, plain or pretty", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    pub time_ns: u128,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check: Option<CheckOutcome>,
+}
+
+/// Everything that happened during a single run of a day.
+#[derive(Debug, Clone, Serialize)]
+pub struct RunReport {
+    pub day: u8,
+    pub input: String,
+    pub parse_ns: Option<u128>,
+    pub error: Option<String>,
+    pub parts: Vec<PartReport>,
+}
+
+/// Reports the phases of a run in the selected [`OutputFormat`]. Pretty and
+/// plain output is printed as the run progresses, JSON output is collected
+/// and printed as one object by [`Output::finish`].
+pub struct Output {
+    format: OutputFormat,
+    report: RunReport,
+}
+
+impl Output {
+    pub fn new(format: OutputFormat, day: u8, input: String) -> Self {
+        Output {
+            format,
+            report: RunReport {
+                day,
+                input,
+                parse_ns: None,
+                error: None,
+                parts: Vec::new(),
+            },
+        }
+    }
+
+    pub fn format(&self) -> OutputFormat {
+        self.format
+    }
+
+    /// The name of the input the run is reported for.
+    pub fn input(&self) -> &str {
+        &self.report.input
+    }
+
+    pub fn parsed<T, E>(&mut self, model: &Result<T, E>, elapsed: Duration)
+    where
+        E: Display,
+    {
+        match self.format {
+            OutputFormat::Pretty => print_duration("Parsing", elapsed),
+            OutputFormat::Plain => {}
+            OutputFormat::Json => {
+                self.report.parse_ns = Some(elapsed.as_nanos());
+                self.report.error = model.as_ref().err().map(ToString::to_string);
+            }
+        }
+    }
+
+    pub fn part<D, E>(&mut self, part: Part, solution: &Result<D, E>, elapsed: Duration)
+    where
+        D: Display,
+        E: Display,
+    {
+        match self.format {
+            OutputFormat::Pretty => {
+                print_solution(&part.to_string(), solution);
+                print_duration(&part.to_string(), elapsed);
+            }
+            OutputFormat::Plain => match solution {
+                Ok(value) => println!("{}", value),
+                Err(error) => eprintln!("Error calculating {}: {}", part, error),
+            },
+            OutputFormat::Json => self.report.parts.push(PartReport {
+                part: part.number(),
+                answer: solution.as_ref().ok().map(ToString::to_string),
+                error: solution.as_ref().err().map(ToString::to_string),
+                time_ns: elapsed.as_nanos(),
+                check: None,
+            }),
+        }
+    }
+
+    /// Records the outcome of `--check` for a part that was already reported.
+    pub fn checked(&mut self, part: Part, outcome: CheckOutcome) {
+        if let Some(report) = self
+            .report
+            .parts
+            .iter_mut()
+            .find(|report| report.part == part.number())
+        {
+            report.check = Some(outcome);
+        }
+    }
+
+    pub fn finish(self) -> crate::error::Result<()> {
+        if self.format == OutputFormat::Json {
+            println!("{}", serde_json::to_string(&self.report)?);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckOutcome {
+    Pass,
+    Fail,
+    New,
+}
+
+/// Compares computed answers against an [`AnswerStore`], as enabled by
+/// `--check`. Answers that are not known yet are only added to the store when
+/// recording, as enabled by `--record`.
+pub struct AnswerCheck {
+    store: AnswerStore,
+    failures: usize,
+    unknown: usize,
+    quiet: bool,
+    record: bool,
+}
+
+impl AnswerCheck {
+    pub fn load<P>(path: P) -> crate::error::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        Ok(AnswerCheck {
+            store: AnswerStore::load(path)?,
+            failures: 0,
+            unknown: 0,
+            quiet: false,
+            record: false,
+        })
+    }
+
+    /// Stops the outcome of each check from being printed, for output formats
+    /// that report it themselves. Failures are still reported on stderr.
+    pub fn quiet(mut self, quiet: bool) -> Self {
+        self.quiet = quiet;
+        self
+    }
+
+    /// Stores answers that are not known yet when finishing.
+    pub fn record(mut self, record: bool) -> Self {
+        self.record = record;
+        self
+    }
+
+    pub fn check<D, E>(
+        &mut self,
+        day: u8,
+        input: &str,
+        part: Part,
+        solution: &Result<D, E>,
+    ) -> CheckOutcome
+    where
+        D: Display,
+        E: Display,
+    {
+        let label = format!("Day {:02} {} ({})", day, part, input);
+        let expected = self.store.get(day, input, part).map(str::to_owned);
+
+        let (outcome, detail) = match (solution, expected) {
+            (Ok(answer), Some(expected)) if answer.to_string() == expected => {
+                (CheckOutcome::Pass, expected)
+            }
+            (Ok(answer), Some(expected)) => (
+                CheckOutcome::Fail,
+                format!("expected {}, got {}", expected, answer),
+            ),
+            (Err(error), Some(expected)) => (
+                CheckOutcome::Fail,
+                format!("expected {}, got error: {}", expected, error),
+            ),
+            (Ok(answer), None) => {
+                let answer = answer.to_string();
+                if self.record {
+                    self.store.insert(day, input, part, answer.clone());
+                }
+                self.unknown += 1;
+                (CheckOutcome::New, answer)
+            }
+            (Err(error), None) => (CheckOutcome::Fail, format!("error: {}", error)),
+        };
+
+        if !self.quiet {
+            print_check(outcome, &label, &detail);
+        } else if outcome == CheckOutcome::Fail {
+            eprintln!("FAIL {}: {}", label, detail);
+        }
+        if outcome == CheckOutcome::Fail {
+            self.failures += 1;
+        }
+        outcome
+    }
+
+    /// Saves newly discovered answers when recording, and fails if any answer
+    /// did not match.
+    pub fn finish(self) -> crate::error::Result<()> {
+        if self.unknown > 0 && self.record {
+            self.store.save()?;
+            tracing::info!(
+                "added {} answer(s) to {}",
+                self.unknown,
+                self.store.path().display()
+            );
+        } else if self.unknown > 0 {
+            tracing::info!(
+                "{} answer(s) not in {}, use --record to add them",
+                self.unknown,
+                self.store.path().display()
+            );
+        }
+
+        if self.failures > 0 {
+            Err(Error::CheckFailed(self.failures))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn print_check(outcome: CheckOutcome, label: &str, detail: &str) {
+    let status = match outcome {
+        CheckOutcome::Pass => style("PASS").bold().green(),
+        CheckOutcome::Fail => style("FAIL").bold().red(),
+        CheckOutcome::New => style("NEW ").bold().yellow(),
+    };
+    println!("{} {}: {}", status, style(label).bright(), detail);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_report() {
+        let mut output = Output::new(OutputFormat::Json, 7, "example".to_owned());
+        output.parsed(&Ok::<_, Error>(()), Duration::from_nanos(10));
+        output.part(Part::One, &Ok::<_, Error>(3749), Duration::from_nanos(20));
+        output.part(
+            Part::Two,
+            &Err::<u64, _>(parse_error("invalid", "x")),
+            Duration::from_nanos(30),
+        );
+        output.checked(Part::One, CheckOutcome::Pass);
+
+        assert_eq!(
+            serde_json::to_string(&output.report).unwrap(),
+            r#"{"day":7,"input":"example","parse_ns":10,"error":null,"parts":[{"part":1,"answer":"3749","error":null,"time_ns":20,"check":"pass"},{"part":2,"answer":null,"error":"Error parsing input: error parsing 'x': invalid","time_ns":30}]}"#
+        );
+    }
+
+    #[test]
+    fn test_output_format() {
+        assert_eq!("json".parse::<OutputFormat>().unwrap(), OutputFormat::Json);
+        assert_eq!(OutputFormat::default(), OutputFormat::Pretty);
+        assert!("yaml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn test_record_answers() {
+        let path = std::env::temp_dir().join(format!("answers_{}.toml", std::process::id()));
+        let answer = Ok::<_, Error>(42);
+
+        let mut check = AnswerCheck::load(&path).unwrap().quiet(true);
+        assert_eq!(
+            check.check(1, "example", Part::One, &answer),
+            CheckOutcome::New
+        );
+        check.finish().unwrap();
+        assert!(!path.exists());
+
+        let mut check = AnswerCheck::load(&path).unwrap().quiet(true).record(true);
+        assert_eq!(
+            check.check(1, "example", Part::One, &answer),
+            CheckOutcome::New
+        );
+        check.finish().unwrap();
+
+        let mut check = AnswerCheck::load(&path).unwrap().quiet(true);
+        assert_eq!(
+            check.check(1, "example", Part::One, &answer),
+            CheckOutcome::Pass
+        );
+        let wrong = Ok::<_, Error>(41);
+        assert_eq!(
+            check.check(1, "example", Part::One, &wrong),
+            CheckOutcome::Fail
+        );
+        assert!(matches!(check.finish(), Err(Error::CheckFailed(1))));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
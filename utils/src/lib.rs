@@ -1,17 +1,32 @@
 pub mod error;
 
 pub mod io {
+    pub mod answers;
     pub mod input;
     pub mod output;
 }
 
+pub mod bench;
+
 pub mod config;
 
+pub mod cycle;
+
 pub mod geom;
+pub mod memo;
+pub mod parse;
+
+pub mod puzzle;
+
+pub mod search;
+
+pub mod solution;
 
 pub mod prelude {
-    pub use crate::config::ExampleSettings;
-    pub use crate::error::{parse_error, Error, Result};
+    pub use crate::config::{Args, Params};
+    pub use crate::error::{parse_error, parse_error_at, value_error, Error, Result};
     pub use crate::io::input::{parse_lines, read_input, read_lines};
     pub use crate::io::output::{init_tracing, print_part_1, print_part_2};
+    pub use crate::puzzle::{Part, Puzzle};
+    pub use crate::solution::{Example, Solution};
 }
@@ -0,0 +1,135 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// A cache for a recursive function, keeping track of how often it was hit.
+///
+/// The function is given to [`Memo::get`] and receives a callback for its
+/// recursive calls, which go through the cache as well:
+///
+/// ```
+/// use utils::memo::Memo;
+///
+/// let fibonacci = |fib: &mut dyn FnMut(u64) -> u64, &n: &u64| {
+///     if n < 2 { n } else { fib(n - 1) + fib(n - 2) }
+/// };
+/// let mut memo = Memo::new("fibonacci");
+/// assert_eq!(memo.get(90, &fibonacci), 2880067194370816120);
+/// assert_eq!(memo.misses(), 91);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    name: &'static str,
+    cache: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<K, V> Memo<K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+{
+    /// An empty cache, the name is used when reporting its statistics.
+    pub fn new(name: &'static str) -> Self {
+        Memo {
+            name,
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// The value of `f` for `key`, computed at most once for every key the
+    /// cache has seen since it was last reset.
+    pub fn get<F>(&mut self, key: K, f: &F) -> V
+    where
+        F: Fn(&mut dyn FnMut(K) -> V, &K) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+
+        self.misses += 1;
+        let value = f(&mut |k| self.get(k, f), &key);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// Clears the cache and its statistics, for use with another input.
+    pub fn reset(&mut self) {
+        self.report();
+        self.cache.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+}
+
+impl<K, V> Memo<K, V> {
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    fn report(&self) {
+        if self.hits + self.misses > 0 {
+            tracing::trace!(
+                hits = self.hits,
+                misses = self.misses,
+                entries = self.cache.len(),
+                "memo {}",
+                self.name
+            );
+        }
+    }
+}
+
+impl<K, V> Drop for Memo<K, V> {
+    fn drop(&mut self) {
+        self.report();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memo() {
+        let calls = std::cell::Cell::new(0);
+        let paths = |paths: &mut dyn FnMut((u8, u8)) -> u64, &(x, y): &(u8, u8)| {
+            calls.set(calls.get() + 1);
+            match (x, y) {
+                (0, _) | (_, 0) => 1,
+                _ => paths((x - 1, y)) + paths((x, y - 1)),
+            }
+        };
+
+        let mut memo = Memo::new("paths");
+        assert!(memo.is_empty());
+        assert_eq!(memo.get((16, 16), &paths), 601080390);
+        assert_eq!(calls.get(), 17 * 17 - 1);
+        assert_eq!(memo.misses(), calls.get());
+        assert_eq!(memo.hits(), 15 * 15);
+        assert_eq!(memo.len(), memo.misses());
+
+        assert_eq!(memo.get((3, 2), &paths), 10);
+        assert_eq!(calls.get(), 17 * 17 - 1);
+
+        memo.reset();
+        assert!(memo.is_empty());
+        assert_eq!((memo.hits(), memo.misses()), (0, 0));
+        assert_eq!(memo.get((3, 2), &paths), 10);
+        assert_eq!(memo.misses(), 11);
+    }
+}
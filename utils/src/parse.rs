@@ -0,0 +1,403 @@
+use std::str::FromStr;
+
+use itertools::Itertools;
+
+use crate::error::{parse_error, value_error, Error, Result};
+
+/// A block of consecutive non-blank lines of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    /// The zero-based line number of the first line of the section.
+    pub start: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Section<'a> {
+    /// The lines of the section, numbered from the start of the input so that
+    /// they can be used for positional errors.
+    pub fn lines(&self) -> impl Iterator<Item = (usize, &'a str)> + 'a {
+        let start = self.start;
+        self.text
+            .lines()
+            .enumerate()
+            .map(move |(i, l)| (start + i, l))
+    }
+
+    /// Parses every line of the section with `f`, pointing errors at their
+    /// line in the whole input, see [`lines`].
+    pub fn parse_lines<T, C, F>(&self, mut f: F) -> Result<C>
+    where
+        C: FromIterator<T>,
+        F: FnMut(&'a str) -> Result<T>,
+    {
+        self.lines()
+            .map(|(y, line)| f(line).map_err(|e| e.in_line(y, line)))
+            .collect()
+    }
+
+    /// Moves the position of an error from parsing the text of this section
+    /// to the matching position in the whole input.
+    pub fn locate(&self, error: Error) -> Error {
+        match error {
+            Error::ParseAt {
+                message,
+                line,
+                column,
+                text,
+                source,
+            } => Error::ParseAt {
+                message,
+                line: line + self.start,
+                column,
+                text,
+                source,
+            },
+            other => other,
+        }
+    }
+}
+
+/// Parses every line of the input with `f`, pointing errors at their line
+/// and, when known, at the text that could not be parsed within it.
+pub fn lines<'a, T, C, F>(input: &'a str, mut f: F) -> Result<C>
+where
+    C: FromIterator<T>,
+    F: FnMut(&'a str) -> Result<T>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(y, line)| f(line).map_err(|e| e.in_line(y, line)))
+        .collect()
+}
+
+/// Splits the input into sections separated by one or more blank lines.
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    let mut sections = Vec::new();
+    let mut current: Option<(usize, usize, usize)> = None;
+    let mut offset = 0;
+
+    for (number, line) in input.split_inclusive('\n').enumerate() {
+        let end = offset + line.len();
+        if line.trim().is_empty() {
+            if let Some((start, from, to)) = current.take() {
+                sections.push(section(input, start, from, to));
+            }
+        } else {
+            let (start, from, _) = current.unwrap_or((number, offset, end));
+            current = Some((start, from, end));
+        }
+        offset = end;
+    }
+    if let Some((start, from, to)) = current {
+        sections.push(section(input, start, from, to));
+    }
+
+    sections
+}
+
+fn section(input: &str, start: usize, from: usize, to: usize) -> Section<'_> {
+    Section {
+        start,
+        text: input[from..to].trim_end_matches(['\n', '\r']),
+    }
+}
+
+/// Splits the input into exactly `N` sections, see [`sections`].
+pub fn sections_array<const N: usize>(input: &str) -> Result<[Section<'_>; N]> {
+    let sections = sections(input);
+    let found = sections.len();
+    sections.try_into().map_err(|_| {
+        parse_error(
+            &format!(
+                "expected {} sections separated by blank lines, found {}",
+                N, found
+            ),
+            input.lines().next().unwrap_or_default(),
+        )
+    })
+}
+
+/// Extracts all integers from a line, ignoring any other text. A `-`
+/// directly in front of a number is taken as its sign, unless it follows
+/// another digit.
+pub fn ints<T>(line: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    Error: From<T::Err>,
+{
+    let bytes = line.as_bytes();
+    let mut numbers = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let negative = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_digit());
+        if negative || bytes[i].is_ascii_digit() {
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            let number = &line[start..i];
+            numbers.push(number.parse().map_err(|e| value_error(number, e))?);
+        } else {
+            i += 1;
+        }
+    }
+
+    Ok(numbers)
+}
+
+/// Extracts exactly `N` integers from a line, see [`ints`].
+pub fn ints_array<T, const N: usize>(line: &str) -> Result<[T; N]>
+where
+    T: FromStr,
+    Error: From<T::Err>,
+{
+    let numbers = ints(line)?;
+    let found = numbers.len();
+    numbers
+        .try_into()
+        .map_err(|_| parse_error(&format!("expected {} integers, found {}", N, found), line))
+}
+
+/// Splits a `key: value` line into its trimmed key and value.
+pub fn key_value(line: &str) -> Result<(&str, &str)> {
+    line.split_once(':')
+        .map(|(key, value)| (key.trim(), value.trim()))
+        .ok_or_else(|| parse_error("expected 'key: value'", line))
+}
+
+/// The value of a `key: value` line, checking that the line has the expected
+/// key.
+pub fn value<'a>(line: &'a str, key: &str) -> Result<&'a str> {
+    match key_value(line)? {
+        (k, value) if k == key => Ok(value),
+        _ => Err(parse_error(&format!("expected '{}: ...'", key), line)),
+    }
+}
+
+/// Parses the value of a `key: value` line, see [`value`].
+pub fn field<T>(line: &str, key: &str) -> Result<T>
+where
+    T: FromStr,
+    Error: From<T::Err>,
+{
+    let value = value(line, key)?;
+    value.parse().map_err(|e| value_error(value, e))
+}
+
+/// Parses a list of values separated by commas, whitespace or both.
+pub fn list<T>(s: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    Error: From<T::Err>,
+{
+    s.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|item| !item.is_empty())
+        .map(|item| item.parse().map_err(|e| value_error(item, e)))
+        .try_collect()
+}
+
+/// Matches a line against a template in which every `{}` stands for a field,
+/// returning the text of the fields. A field extends up to the first
+/// occurrence of the literal text that follows it in the template, or to the
+/// end of the line for a trailing field. Used by [`scan!`](crate::scan).
+pub fn scan_fields<'a>(line: &'a str, template: &str) -> Result<Vec<&'a str>> {
+    let mut literals = template.split("{}");
+    let first = literals.next().unwrap_or_default();
+    let mut rest = line
+        .strip_prefix(first)
+        .ok_or_else(|| parse_error(&format!("expected '{}'", first), line))?;
+
+    let mut fields = Vec::new();
+    for literal in literals {
+        let end = if literal.is_empty() {
+            rest.len()
+        } else {
+            rest.find(literal)
+                .ok_or_else(|| parse_error(&format!("expected '{}'", literal), rest))?
+        };
+        if end == 0 {
+            return Err(parse_error(
+                &format!("expected a value for field {}", fields.len() + 1),
+                rest,
+            ));
+        }
+        fields.push(&rest[..end]);
+        rest = &rest[end + literal.len()..];
+    }
+
+    if rest.is_empty() {
+        Ok(fields)
+    } else {
+        Err(parse_error("unexpected text", rest))
+    }
+}
+
+/// Parses a single field matched by [`scan_fields`].
+pub fn scan_field<T>(field: Option<&str>, line: &str) -> Result<T>
+where
+    T: FromStr,
+    Error: From<T::Err>,
+{
+    let field = field.ok_or_else(|| parse_error("template has too few fields", line))?;
+    field.parse().map_err(|e| value_error(field, e))
+}
+
+/// Parses the fields of a line according to a template, returning them as a
+/// tuple. Every `{}` in the template is parsed with [`FromStr`] into the type
+/// at the same position.
+///
+/// ```
+/// let (px, py, vx, vy) = utils::scan!("p=0,4 v=3,-3", "p={},{} v={},{}" => i16, i16, i16, i16)?;
+/// assert_eq!((px, py, vx, vy), (0, 4, 3, -3));
+/// # Ok::<(), utils::error::Error>(())
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($line:expr, $template:expr => $($ty:ty),+ $(,)?) => {{
+        let line: &str = $line;
+        $crate::parse::scan_fields(line, $template).and_then(|fields| {
+            let mut fields = fields.into_iter();
+            let values = ($($crate::parse::scan_field::<$ty>(fields.next(), line)?,)+);
+            match fields.next() {
+                Some(_) => Err($crate::error::parse_error("template has too many fields", line)),
+                None => Ok(values),
+            }
+        })
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sections() {
+        let input = "a\nb\n\n\n  \nc\r\n\r\nd\n";
+        let sections = sections(input);
+        assert_eq!(
+            sections,
+            vec![
+                Section {
+                    start: 0,
+                    text: "a\nb"
+                },
+                Section {
+                    start: 5,
+                    text: "c"
+                },
+                Section {
+                    start: 7,
+                    text: "d"
+                },
+            ]
+        );
+        assert_eq!(sections[0].lines().collect_vec(), vec![(0, "a"), (1, "b")]);
+
+        let error = sections[1].locate(crate::error::parse_error_at("invalid", 0, 0, "c"));
+        assert!(matches!(error, Error::ParseAt { line: 6, .. }));
+
+        assert!(sections_array::<2>(input).is_err());
+        assert_eq!(sections_array::<1>("\nx\n").unwrap()[0].text, "x");
+        assert!(super::sections("").is_empty());
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(ints::<i16>("p=0,4 v=3,-3").unwrap(), vec![0, 4, 3, -3]);
+        assert_eq!(ints::<i64>("Button A: X+94, Y+34").unwrap(), vec![94, 34]);
+        assert_eq!(ints::<i32>("1-3 a-5--6").unwrap(), vec![1, 3, -5, -6]);
+        assert!(ints::<u8>("-1").is_err());
+        assert!(ints::<u8>("300").is_err());
+
+        assert_eq!(ints_array::<u32, 2>("47|53").unwrap(), [47, 53]);
+        assert!(ints_array::<u32, 2>("47|53|1").is_err());
+    }
+
+    #[test]
+    fn test_key_value() {
+        assert_eq!(key_value("Register A: 729").unwrap(), ("Register A", "729"));
+        assert_eq!(field::<u64>("Register A: 729", "Register A").unwrap(), 729);
+        assert!(field::<u64>("Register B: 729", "Register A").is_err());
+        assert!(key_value("Register A 729").is_err());
+    }
+
+    #[test]
+    fn test_scan() {
+        assert_eq!(
+            crate::scan!("Button A: X+94, Y+34", "Button A: X+{}, Y+{}" => i64, i64).unwrap(),
+            (94, 34)
+        );
+        assert_eq!(
+            crate::scan!("190: 10 19", "{}: {}" => u64, String).unwrap(),
+            (190, "10 19".to_owned())
+        );
+        assert_eq!(crate::scan!("42", "{}" => u8).unwrap(), (42,));
+
+        assert!(crate::scan!("Button B: X+94, Y+34", "Button A: X+{}, Y+{}" => i64, i64).is_err());
+        assert!(crate::scan!("p=0,4", "p={},{} v={},{}" => i16, i16, i16, i16).is_err());
+        let error = crate::scan!("p=0,x", "p={},{}" => i16, i16).unwrap_err();
+        assert!(
+            std::error::Error::source(&error).is_some_and(|e| e.is::<std::num::ParseIntError>())
+        );
+        assert!(crate::scan!("p=0,4 extra", "p={},{}" => i16, u8).is_err());
+        assert!(crate::scan!("p=,4", "p={},{}" => i16, i16).is_err());
+        assert!(crate::scan!("p=1,4", "p={},{}" => i16, i16, i16).is_err());
+        assert!(crate::scan!("p=1,4", "p={},{}" => i16).is_err());
+    }
+
+    #[test]
+    fn test_lines() {
+        let numbers: Vec<Vec<u8>> = lines("1,2\n3", list).unwrap();
+        assert_eq!(numbers, vec![vec![1, 2], vec![3]]);
+
+        let error = lines::<Vec<u8>, Vec<_>, _>("1,2\n3,x4", list).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::ParseAt {
+                line: 2,
+                column: 3,
+                ..
+            }
+        ));
+
+        let error = lines::<_, Vec<_>, _>("p=1,2\np=3,-", |l| crate::scan!(l, "p={},{}" => u8, u8))
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::ParseAt {
+                line: 2,
+                column: 5,
+                ..
+            }
+        ));
+
+        let input = "a\n\n7\n8x";
+        let section = sections(input)[1];
+        let error =
+            section.parse_lines::<u8, Vec<_>, _>(|l| l.parse().map_err(|e| value_error(l, e)));
+        assert!(matches!(
+            error,
+            Err(Error::ParseAt {
+                line: 4,
+                column: 1,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_list() {
+        assert_eq!(list::<u8>("0,1, 5 4\t3").unwrap(), vec![0, 1, 5, 4, 3]);
+        assert_eq!(
+            list::<String>("r, wr, b").unwrap(),
+            vec!["r".to_owned(), "wr".to_owned(), "b".to_owned()]
+        );
+        assert!(list::<u8>("1,x").is_err());
+        assert!(list::<u8>("").unwrap().is_empty());
+    }
+}
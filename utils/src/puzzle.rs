@@ -0,0 +1,75 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::bench::PhaseTiming;
+use crate::config::Args;
+use crate::error::Result;
+use crate::io::output::{AnswerCheck, Output};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn iter() -> impl Iterator<Item = Part> {
+        [Part::One, Part::Two].into_iter()
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = crate::error::Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(crate::error::parse_error(
+                "part should be 1 or 2",
+                &value.to_string(),
+            )),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Part {}", self.number())
+    }
+}
+
+/// The answers of a single run of a day, rendered as strings, with the time
+/// each phase took.
+#[derive(Debug)]
+pub struct Answers {
+    pub parse: Duration,
+    pub parts: Vec<(Part, Result<String>, Duration)>,
+}
+
+/// The solver of a single day, with the answers rendered as strings so that
+/// different days can be driven through the same interface.
+pub trait Puzzle {
+    fn day(&self) -> u8;
+
+    /// Parses the input once, with the parameters selected by `args`, and
+    /// solves the parts selected by `args`. Every phase is reported to
+    /// `output`, and the answers are compared through `check` if given.
+    fn solve(
+        &self,
+        input: &str,
+        args: &Args,
+        output: &mut Output,
+        check: Option<&mut AnswerCheck>,
+    ) -> Result<Answers>;
+
+    /// Times every phase over a number of runs, see
+    /// [`bench`](crate::bench::bench).
+    fn bench(&self, input: &str, args: &Args, runs: usize) -> Result<Vec<PhaseTiming>>;
+}
@@ -0,0 +1,593 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::{Add, Range},
+};
+
+use num::{traits::Zero, PrimInt};
+
+/// The outcome of a search: the cost of the cheapest known path to every
+/// state that was reached, and the state before each of them on that path.
+#[derive(Debug, Clone)]
+pub struct SearchResult<S, C> {
+    pub distances: HashMap<S, C>,
+    pub predecessors: HashMap<S, S>,
+    /// The first goal state that was settled, if any.
+    pub goal: Option<S>,
+}
+
+impl<S, C> SearchResult<S, C>
+where
+    S: Clone + Hash + Eq,
+    C: Copy,
+{
+    fn new() -> Self {
+        SearchResult {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    /// The cost of the cheapest path to the goal.
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goal.as_ref().and_then(|goal| self.cost(goal))
+    }
+
+    /// The cheapest path from a start state to `state`, including both.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return None;
+        }
+
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessors.get(path.last()?) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The cheapest path from a start state to the goal.
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.goal.as_ref().and_then(|goal| self.path_to(goal))
+    }
+}
+
+/// Breadth-first search where every step costs one. Stops as soon as a state
+/// for which `is_goal` holds is reached; pass `|_| false` to explore
+/// everything reachable from the starts.
+pub fn bfs<S, I, N, SI, G>(starts: I, mut successors: N, mut is_goal: G) -> SearchResult<S, usize>
+where
+    S: Clone + Hash + Eq,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> SI,
+    SI: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    let mut result = SearchResult::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if result.distances.insert(start.clone(), 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            result.goal = Some(state);
+            break;
+        }
+
+        let steps = result.distances[&state] + 1;
+        for next in successors(&state) {
+            if !result.distances.contains_key(&next) {
+                result.distances.insert(next.clone(), steps);
+                result.predecessors.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    result
+}
+
+/// Dijkstra's algorithm with `successors` returning the next states together
+/// with the cost of getting there. Stops when a goal state is settled.
+pub fn dijkstra<S, C, I, N, SI, G>(starts: I, successors: N, is_goal: G) -> SearchResult<S, C>
+where
+    S: Clone + Hash + Eq,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> SI,
+    SI: IntoIterator<Item = (S, C)>,
+    G: FnMut(&S) -> bool,
+{
+    astar(starts, successors, |_| C::zero(), is_goal)
+}
+
+/// A* search, like [`dijkstra`] but guided by `heuristic`, which must never
+/// overestimate the remaining cost to a goal.
+pub fn astar<S, C, I, N, SI, H, G>(
+    starts: I,
+    mut successors: N,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> SearchResult<S, C>
+where
+    S: Clone + Hash + Eq,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> SI,
+    SI: IntoIterator<Item = (S, C)>,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let mut result = SearchResult::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        result.distances.insert(start.clone(), C::zero());
+        queue.push(Queued {
+            priority: heuristic(&start),
+            cost: C::zero(),
+            state: start,
+        });
+    }
+
+    while let Some(Queued { cost, state, .. }) = queue.pop() {
+        if result
+            .distances
+            .get(&state)
+            .is_some_and(|&best| cost > best)
+        {
+            continue;
+        }
+        if is_goal(&state) {
+            result.goal = Some(state);
+            break;
+        }
+
+        for (next, step) in successors(&state) {
+            let cost = cost + step;
+            if result.distances.get(&next).is_none_or(|&best| cost < best) {
+                result.distances.insert(next.clone(), cost);
+                result.predecessors.insert(next.clone(), state.clone());
+                queue.push(Queued {
+                    priority: cost + heuristic(&next),
+                    cost,
+                    state: next,
+                });
+            }
+        }
+    }
+
+    result
+}
+
+/// The outcome of a search that keeps every cheapest path instead of just
+/// one: each reached state has all predecessors that lie on one of its
+/// cheapest paths, which together form a directed acyclic graph.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<S, C> {
+    pub distances: HashMap<S, C>,
+    pub predecessors: HashMap<S, Vec<S>>,
+    /// All goal states that can be reached at the lowest cost.
+    pub goals: Vec<S>,
+}
+
+impl<S, C> ShortestPaths<S, C>
+where
+    S: Clone + Hash + Eq,
+    C: Copy + Ord,
+{
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goals.first().and_then(|goal| self.cost(goal))
+    }
+
+    fn predecessors_of(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// Every state that lies on at least one cheapest path to a goal.
+    pub fn states_on_paths(&self) -> HashSet<S> {
+        let mut states: HashSet<S> = self.goals.iter().cloned().collect();
+        let mut stack = self.goals.clone();
+        while let Some(state) = stack.pop() {
+            for previous in self.predecessors_of(&state) {
+                if states.insert(previous.clone()) {
+                    stack.push(previous.clone());
+                }
+            }
+        }
+        states
+    }
+
+    /// Every step `(from, to)` that is part of at least one cheapest path to
+    /// a goal.
+    pub fn edges_on_paths(&self) -> HashSet<(S, S)> {
+        self.states_on_paths()
+            .into_iter()
+            .flat_map(|state| {
+                self.predecessors_of(&state)
+                    .iter()
+                    .map(move |previous| (previous.clone(), state.clone()))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// The number of distinct cheapest paths from any start to any goal.
+    pub fn count_paths(&self) -> u128 {
+        let mut states = self.states_on_paths().into_iter().collect::<Vec<_>>();
+        states.sort_by_key(|state| self.distances[state]);
+
+        let mut counts: HashMap<S, u128> = HashMap::new();
+        for state in states {
+            let predecessors = self.predecessors_of(&state);
+            let count = if predecessors.is_empty() {
+                1
+            } else {
+                predecessors.iter().map(|p| counts[p]).sum()
+            };
+            counts.insert(state, count);
+        }
+
+        self.goals.iter().map(|goal| counts[goal]).sum()
+    }
+
+    /// One cheapest path to a goal, from the start to the goal. At every
+    /// state with several predecessors, `choose` is called with their number
+    /// and returns the index of the one to follow.
+    pub fn sample_path<F>(&self, mut choose: F) -> Option<Vec<S>>
+    where
+        F: FnMut(usize) -> usize,
+    {
+        let last = self.goals.len().checked_sub(1)?;
+        let mut path = vec![self.goals[choose(self.goals.len()).min(last)].clone()];
+        loop {
+            let predecessors = self.predecessors_of(path.last()?);
+            if predecessors.is_empty() {
+                break;
+            }
+            let index = choose(predecessors.len()).min(predecessors.len() - 1);
+            path.push(predecessors[index].clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The cheapest path that always follows the first predecessor.
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.sample_path(|_| 0)
+    }
+}
+
+/// Dijkstra's algorithm that records every cheapest path to every state and
+/// collects all goal states reachable at the lowest cost, rather than
+/// stopping at the first one. Goal states are not expanded further. Step costs
+/// must be positive.
+pub fn dijkstra_all<S, C, I, N, SI, G>(
+    starts: I,
+    mut successors: N,
+    mut is_goal: G,
+) -> ShortestPaths<S, C>
+where
+    S: Clone + Hash + Eq,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> SI,
+    SI: IntoIterator<Item = (S, C)>,
+    G: FnMut(&S) -> bool,
+{
+    let mut result = ShortestPaths {
+        distances: HashMap::new(),
+        predecessors: HashMap::new(),
+        goals: Vec::new(),
+    };
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        result.distances.insert(start.clone(), C::zero());
+        queue.push(Queued {
+            priority: C::zero(),
+            cost: C::zero(),
+            state: start,
+        });
+    }
+
+    while let Some(Queued { cost, state, .. }) = queue.pop() {
+        if result
+            .distances
+            .get(&state)
+            .is_some_and(|&best| cost > best)
+        {
+            continue;
+        }
+        if result.goal_cost().is_some_and(|goal_cost| cost > goal_cost) {
+            break;
+        }
+        if is_goal(&state) {
+            result.goals.push(state);
+            continue;
+        }
+
+        for (next, step) in successors(&state) {
+            let cost = cost + step;
+            match result.distances.get(&next) {
+                Some(&best) if cost > best => {}
+                Some(&best) if cost == best => {
+                    result
+                        .predecessors
+                        .entry(next)
+                        .or_default()
+                        .push(state.clone());
+                }
+                _ => {
+                    result.distances.insert(next.clone(), cost);
+                    result
+                        .predecessors
+                        .insert(next.clone(), vec![state.clone()]);
+                    queue.push(Queued {
+                        priority: cost,
+                        cost,
+                        state: next,
+                    });
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// An entry of the priority queue, ordered so that the lowest priority is
+/// popped first from the max-heap.
+struct Queued<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+/// The first value in `range` for which `predicate` holds, using a binary
+/// search. The predicate must be monotone: false up to some value and true
+/// from there on.
+pub fn first_true<T, P>(range: Range<T>, mut predicate: P) -> Option<T>
+where
+    T: PrimInt,
+    P: FnMut(T) -> bool,
+{
+    let (mut low, mut high) = (range.start, range.end);
+    while low < high {
+        let mid = midpoint(low, high);
+        if predicate(mid) {
+            high = mid;
+        } else {
+            low = mid + T::one();
+        }
+    }
+    (low < range.end).then_some(low)
+}
+
+/// The last value in `range` for which `predicate` holds, using a binary
+/// search. The predicate must be monotone: true up to some value and false
+/// from there on.
+pub fn last_true<T, P>(range: Range<T>, mut predicate: P) -> Option<T>
+where
+    T: PrimInt,
+    P: FnMut(T) -> bool,
+{
+    match first_true(range.clone(), |value| !predicate(value)) {
+        Some(first_false) => (first_false > range.start).then(|| first_false - T::one()),
+        None => (range.start < range.end).then(|| range.end - T::one()),
+    }
+}
+
+/// The first value from `start` on for which the monotone `predicate` holds,
+/// see [`first_true`]. Probes at exponentially growing distances to find an
+/// upper bound first, so the answer does not need to be bounded in advance.
+pub fn first_true_from<T, P>(start: T, mut predicate: P) -> Option<T>
+where
+    T: PrimInt,
+    P: FnMut(T) -> bool,
+{
+    let mut low = start;
+    let mut step = T::zero();
+    loop {
+        let probe = low.saturating_add(step);
+        if predicate(probe) {
+            return first_true(low..probe, &mut predicate).or(Some(probe));
+        } else if probe == T::max_value() {
+            return None;
+        }
+        low = probe + T::one();
+        step = step.saturating_add(step).max(T::one());
+    }
+}
+
+/// The mean of `a` and `b`, rounded down, without overflowing.
+fn midpoint<T: PrimInt>(a: T, b: T) -> T {
+    (a & b) + ((a ^ b) >> 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A small weighted graph in which the cheapest path from 0 to 4, through
+    /// 2 and 3, has more steps than the shortest one.
+    fn edges(node: &u8) -> Vec<(u8, u32)> {
+        match node {
+            0 => vec![(1, 1), (2, 1)],
+            1 => vec![(3, 5)],
+            2 => vec![(3, 1), (4, 10)],
+            3 => vec![(4, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let successors = |n: &u8| edges(n).into_iter().map(|(n, _)| n);
+
+        let result = bfs([0], successors, |&n| n == 4);
+        assert_eq!(result.goal_cost(), Some(2));
+        assert_eq!(result.path(), Some(vec![0, 2, 4]));
+
+        let result = bfs([3], successors, |_| false);
+        assert_eq!(result.goal, None);
+        assert_eq!(result.distances, HashMap::from([(3, 0), (4, 1)]));
+        assert_eq!(result.path_to(&0), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let result = dijkstra([0], edges, |&n| n == 4);
+        assert_eq!(result.goal_cost(), Some(3));
+        assert_eq!(result.path(), Some(vec![0, 2, 3, 4]));
+
+        let result = dijkstra([1, 2], edges, |_| false);
+        assert_eq!(result.cost(&3), Some(1));
+        assert_eq!(result.cost(&0), None);
+        assert_eq!(result.path_to(&4), Some(vec![2, 3, 4]));
+    }
+
+    #[test]
+    fn test_astar() {
+        type Point = crate::geom::Point<i32>;
+
+        let wall = |p: &Point| p.x == 2 && p.y < 4;
+        let successors = |p: &Point| {
+            p.neighbours()
+                .filter(|n| (0..6).contains(&n.x) && (0..6).contains(&n.y) && !wall(n))
+                .map(|n| (n, 1))
+                .collect::<Vec<_>>()
+        };
+        let goal = Point::new(4, 0);
+        let manhattan = |p: &Point| (p.x - goal.x).abs() + (p.y - goal.y).abs();
+
+        let result = astar([Point::new(0, 0)], successors, manhattan, |p| *p == goal);
+        assert_eq!(result.goal_cost(), Some(12));
+        assert_eq!(result.path().map(|path| path.len()), Some(13));
+        assert_eq!(
+            dijkstra([Point::new(0, 0)], successors, |p| *p == goal).goal_cost(),
+            Some(12)
+        );
+    }
+
+    /// A grid of `width` by `height` open cells, moving right or down only, so
+    /// that every path from the top left to the bottom right is a cheapest one.
+    fn lattice(width: i32, height: i32) -> ShortestPaths<(i32, i32), u32> {
+        dijkstra_all(
+            [(0, 0)],
+            |&(x, y)| {
+                [(x + 1, y), (x, y + 1)]
+                    .into_iter()
+                    .filter(|&(x, y)| x < width && y < height)
+                    .map(|p| (p, 1))
+            },
+            |&p| p == (width - 1, height - 1),
+        )
+    }
+
+    #[test]
+    fn test_dijkstra_all() {
+        let paths = dijkstra_all([0], edges, |&n| n == 4);
+        assert_eq!(paths.goal_cost(), Some(3));
+        assert_eq!(paths.count_paths(), 1);
+        assert_eq!(paths.states_on_paths(), HashSet::from([0, 2, 3, 4]));
+        assert_eq!(paths.path(), Some(vec![0, 2, 3, 4]));
+
+        let paths = lattice(3, 3);
+        assert_eq!(paths.goal_cost(), Some(4));
+        assert_eq!(paths.count_paths(), 6);
+        assert_eq!(paths.states_on_paths().len(), 9);
+        assert_eq!(paths.edges_on_paths().len(), 12);
+        assert_eq!(
+            paths.sample_path(|n| n - 1),
+            Some(vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)])
+        );
+        assert_eq!(lattice(4, 5).count_paths(), 35);
+
+        let paths = dijkstra_all([0], edges, |&n| n == 1 || n == 2);
+        assert_eq!(paths.goals.len(), 2);
+        assert_eq!(paths.count_paths(), 2);
+
+        let paths = dijkstra_all([4], edges, |&n| n == 0);
+        assert_eq!(paths.goal_cost(), None);
+        assert_eq!(paths.count_paths(), 0);
+        assert_eq!(paths.path(), None);
+    }
+
+    #[test]
+    fn test_first_true() {
+        assert_eq!(first_true(0..100, |x: u32| x >= 37), Some(37));
+        assert_eq!(first_true(0..100, |_: u32| true), Some(0));
+        assert_eq!(first_true(0..100, |_: u32| false), None);
+        assert_eq!(first_true(5..5, |_: u32| true), None);
+        assert_eq!(first_true(5..6, |_: u32| true), Some(5));
+        assert_eq!(first_true(-50..50, |x: i32| x >= -7), Some(-7));
+        assert_eq!(first_true(i8::MIN..i8::MAX, |x| x >= 100), Some(100));
+        assert_eq!(
+            first_true(0..u64::MAX, |x| x >= u64::MAX - 1),
+            Some(u64::MAX - 1)
+        );
+
+        let mut calls = 0;
+        first_true(0..1024, |x: u32| {
+            calls += 1;
+            x >= 1000
+        });
+        assert_eq!(calls, 10);
+    }
+
+    #[test]
+    fn test_last_true() {
+        assert_eq!(last_true(0..100, |x: u32| x * x <= 50), Some(7));
+        assert_eq!(last_true(0..100, |_: u32| true), Some(99));
+        assert_eq!(last_true(0..100, |_: u32| false), None);
+        assert_eq!(last_true(3..3, |_: u32| true), None);
+        assert_eq!(last_true(i8::MIN..i8::MAX, |x| x < -100), Some(-101));
+    }
+
+    #[test]
+    fn test_first_true_from() {
+        assert_eq!(
+            first_true_from(0, |x: u64| x >= 1_000_000_007),
+            Some(1_000_000_007)
+        );
+        assert_eq!(first_true_from(10, |_: u32| true), Some(10));
+        assert_eq!(first_true_from(10, |x: u32| x >= 11), Some(11));
+        assert_eq!(first_true_from(-20, |x: i64| x >= -3), Some(-3));
+        assert_eq!(first_true_from(0, |x: u8| x == u8::MAX), Some(u8::MAX));
+        assert_eq!(first_true_from(0, |_: u8| false), None);
+        assert_eq!(first_true_from(u8::MAX, |_: u8| false), None);
+    }
+}
@@ -0,0 +1,237 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use crate::bench::{bench, print_bench, PhaseTiming};
+use crate::config::{Args, Params};
+use crate::error::Result;
+use crate::io::input::InputSource;
+use crate::io::output::{init_tracing, AnswerCheck, Output, OutputFormat};
+use crate::puzzle::{Answers, Part, Puzzle};
+
+/// A typed solution for a single day: the input is parsed once into a model
+/// which is then shared by both parts.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    /// Puzzle parameters that are not part of the input itself and differ
+    /// between the examples and the real puzzle, such as the size of a room.
+    type Params: Params;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str, params: &Self::Params) -> Result<Self::Input>;
+
+    fn part_one(input: &Self::Input) -> Result<Self::Answer1>;
+
+    fn part_two(input: &Self::Input) -> Result<Self::Answer2>;
+
+    /// The examples from the puzzle description with their expected answers.
+    fn examples() -> Vec<Example<Self>>
+    where
+        Self: Sized;
+}
+
+/// An example input with the answers given in the puzzle description.
+pub struct Example<S>
+where
+    S: Solution,
+{
+    pub name: &'static str,
+    pub input: &'static str,
+    pub params: S::Params,
+    pub part_one: Option<&'static str>,
+    pub part_two: Option<&'static str>,
+}
+
+impl<S> Example<S>
+where
+    S: Solution,
+{
+    pub fn new(name: &'static str, input: &'static str) -> Self {
+        Example {
+            name,
+            input,
+            params: S::Params::example(),
+            part_one: None,
+            part_two: None,
+        }
+    }
+
+    pub fn params(self, params: S::Params) -> Self {
+        Example { params, ..self }
+    }
+
+    pub fn part_one(self, answer: &'static str) -> Self {
+        Example {
+            part_one: Some(answer),
+            ..self
+        }
+    }
+
+    pub fn part_two(self, answer: &'static str) -> Self {
+        Example {
+            part_two: Some(answer),
+            ..self
+        }
+    }
+}
+
+/// Runs a solution on all of its examples, panicking on the first answer that
+/// differs from the expected one.
+pub fn check_examples<S>()
+where
+    S: Solution,
+{
+    let examples = S::examples();
+    assert!(!examples.is_empty(), "day {:02} has no examples", S::DAY);
+
+    for example in examples {
+        let label = format!("day {:02} example '{}'", S::DAY, example.name);
+        let input = S::parse(example.input, &example.params)
+            .unwrap_or_else(|e| panic!("{}: error parsing input: {}", label, e));
+
+        if let Some(expected) = example.part_one {
+            match S::part_one(&input) {
+                Ok(answer) => assert_eq!(answer.to_string(), expected, "{} part 1", label),
+                Err(e) => panic!("{} part 1: {}", label, e),
+            }
+        }
+
+        if let Some(expected) = example.part_two {
+            match S::part_two(&input) {
+                Ok(answer) => assert_eq!(answer.to_string(), expected, "{} part 2", label),
+                Err(e) => panic!("{} part 2: {}", label, e),
+            }
+        }
+    }
+}
+
+/// Generates a test running [`check_examples`] for the given solution.
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ident) => {
+        #[cfg(test)]
+        mod examples {
+            #[test]
+            fn test_examples() {
+                $crate::solution::check_examples::<super::$solution>();
+            }
+        }
+    };
+}
+
+impl<S> Puzzle for S
+where
+    S: Solution,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn solve(
+        &self,
+        input: &str,
+        args: &Args,
+        output: &mut Output,
+        check: Option<&mut AnswerCheck>,
+    ) -> Result<Answers> {
+        solve::<S>(input, args, output, check)
+    }
+
+    fn bench(&self, input: &str, args: &Args, runs: usize) -> Result<Vec<PhaseTiming>> {
+        bench::<S>(input, &S::Params::from_args(args)?, runs)
+    }
+}
+
+pub fn timed<F, T>(f: F) -> (T, Duration)
+where
+    F: FnOnce() -> T,
+{
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Runs both parts of a solution on the input given on the command line (or
+/// stdin), printing the answers together with the time each phase took.
+///
+/// With `--bench N` every phase is instead run `N` times and summarised, in the
+/// format selected by `--bench-format table|csv|json`.
+///
+/// With `--check` the answers are compared to the ones stored in
+/// `answers.toml` (or the file given by `--answers`). Answers that are not
+/// stored yet are only added with `--record`.
+pub fn run<S>(args: &Args) -> Result<()>
+where
+    S: Solution,
+{
+    init_tracing();
+
+    let mut check = if args.check {
+        Some(
+            AnswerCheck::load(&args.answers)?
+                .quiet(args.format != OutputFormat::Pretty)
+                .record(args.record),
+        )
+    } else {
+        None
+    };
+
+    let source = InputSource::locate(S::DAY, args)?;
+    let input = source.read()?;
+
+    if let Some(runs) = args.bench {
+        let params = S::Params::from_args(args)?;
+        return print_bench(&bench::<S>(&input, &params, runs)?, args.bench_format);
+    }
+
+    let mut output = Output::new(args.format, S::DAY, source.name());
+    let solved = solve::<S>(&input, args, &mut output, check.as_mut());
+    output.finish()?;
+    solved?;
+
+    check.map_or(Ok(()), AnswerCheck::finish)
+}
+
+/// Parses the input once, with the parameters selected by `args`, and solves
+/// the parts selected by `args`, reporting every phase to `output`. With a
+/// `check`, the answers are also compared to the stored ones.
+pub fn solve<S>(
+    input: &str,
+    args: &Args,
+    output: &mut Output,
+    check: Option<&mut AnswerCheck>,
+) -> Result<Answers>
+where
+    S: Solution,
+{
+    let params = S::Params::from_args(args)?;
+
+    let (model, parse) = timed(|| S::parse(input, &params));
+    output.parsed(&model, parse);
+    let model = model?;
+
+    let mut parts = Vec::new();
+    if args.runs(Part::One) {
+        let (answer, elapsed) = timed(|| S::part_one(&model));
+        output.part(Part::One, &answer, elapsed);
+        parts.push((Part::One, answer.map(|a| a.to_string()), elapsed));
+    }
+    if args.runs(Part::Two) {
+        let (answer, elapsed) = timed(|| S::part_two(&model));
+        output.part(Part::Two, &answer, elapsed);
+        parts.push((Part::Two, answer.map(|a| a.to_string()), elapsed));
+    }
+
+    if let Some(check) = check {
+        for (part, answer, _) in &parts {
+            let outcome = check.check(S::DAY, output.input(), *part, answer);
+            output.checked(*part, outcome);
+        }
+    }
+
+    Ok(Answers { parse, parts })
+}